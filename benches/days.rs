@@ -0,0 +1,62 @@
+//! Benchmarks for every registered day, with parse, part_1 and part_2
+//! measured separately. Each day runs on its real input when it has been
+//! pulled, and on the example from the puzzle otherwise. Parts which are
+//! not implemented yet are skipped.
+//!
+//! Save a baseline before a change and compare against it after:
+//!
+//! ```text
+//! cargo bench --bench days -- --save-baseline before
+//! cargo bench --bench days -- --baseline before
+//! ```
+
+use advent_2025::{
+    puzzle::{DayVisitor, Solution, Unimplemented, read_input},
+    y2025,
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+type Part<S> = fn(&<S as Solution>::Input) -> Result<String, anyhow::Error>;
+
+struct Bench<'a> {
+    c: &'a mut Criterion,
+}
+
+impl DayVisitor for Bench<'_> {
+    fn visit<S: Solution>(&mut self, year: u16, day: u8) {
+        let raw = match read_input(year, day) {
+            Ok(raw) => raw,
+            Err(_) => {
+                eprintln!("{} {}: no input, using the example", year, S::NAME);
+                S::EXAMPLE.to_string()
+            }
+        };
+
+        let mut group = self.c.benchmark_group(format!("{}/day_{:02}", year, day));
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw))));
+
+        match S::parse(&raw) {
+            Ok(input) => {
+                let parts: [(&str, Part<S>); 2] = [("part_1", S::part_1), ("part_2", S::part_2)];
+                for (name, part) in parts {
+                    if matches!(part(&input), Err(e) if e.is::<Unimplemented>()) {
+                        eprintln!("{} {}: {} is not implemented", year, S::NAME, name);
+                        continue;
+                    }
+                    group.bench_function(name, |b| b.iter(|| part(black_box(&input))));
+                }
+            }
+            Err(e) => eprintln!("{} {}: skipping the parts: {:#}", year, S::NAME, e),
+        }
+
+        group.finish();
+    }
+}
+
+fn days(c: &mut Criterion) {
+    y2025::for_each_day(&mut Bench { c });
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
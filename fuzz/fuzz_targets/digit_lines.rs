@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_2025::parse;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = parse::digit_lines(input);
+    }
+});
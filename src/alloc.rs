@@ -0,0 +1,159 @@
+//! Allocation counting for `aoc run --stats`.
+//!
+//! With the `alloc-stats` feature a counting allocator wraps the system
+//! allocator. The counters are shared by every thread, so measurements are
+//! only meaningful while one thing runs at a time.
+
+use std::fmt;
+
+/// AllocStats records the allocations made while running a closure.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    /// The most memory in use at once, above what was in use beforehand.
+    pub peak_bytes: usize,
+    /// The sum of every allocation, including those later freed.
+    pub total_bytes: usize,
+    /// The number of allocations.
+    pub count: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} total, {} peak",
+            self.count,
+            format_bytes(self.total_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes < 1 << 10 {
+        format!("{}B", bytes)
+    } else if bytes < 1 << 20 {
+        format!("{:.2}KiB", bytes as f64 / (1 << 10) as f64)
+    } else if bytes < 1 << 30 {
+        format!("{:.2}MiB", bytes as f64 / (1 << 20) as f64)
+    } else {
+        format!("{:.2}GiB", bytes as f64 / (1 << 30) as f64)
+    }
+}
+
+/// Runs `f`, counting its allocations. Without the `alloc-stats` feature
+/// nothing is counted and the stats are `None`.
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    counting::measure(f)
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    static TOTAL: AtomicUsize = AtomicUsize::new(0);
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    impl Counting {
+        fn record(&self, size: usize) {
+            let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+            TOTAL.fetch_add(size, Ordering::Relaxed);
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // SAFETY: every call is forwarded to the system allocator unchanged; the
+    // counters only observe the sizes.
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                self.record(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                self.record(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+                self.record(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+        let start = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(start, Ordering::Relaxed);
+        let total = TOTAL.load(Ordering::Relaxed);
+        let count = COUNT.load(Ordering::Relaxed);
+
+        let result = f();
+
+        let stats = AllocStats {
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+            total_bytes: TOTAL.load(Ordering::Relaxed) - total,
+            count: COUNT.load(Ordering::Relaxed) - count,
+        };
+        (result, Some(stats))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure_counts_allocations() {
+        let (_, stats) = measure(|| {
+            let big = vec![0u8; 1 << 20];
+            drop(big);
+            let small = vec![0u8; 1 << 10];
+            small.len()
+        });
+        let stats = stats.unwrap();
+        assert!(stats.count >= 2);
+        assert!(stats.total_bytes >= (1 << 20) + (1 << 10));
+        assert!(stats.peak_bytes >= 1 << 20);
+    }
+}
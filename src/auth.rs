@@ -0,0 +1,111 @@
+//! `aoc auth`: saving and checking the session token.
+
+use crate::{
+    client::AocClient,
+    config::{Config, SESSION_FILE, user_dir},
+};
+use anyhow::anyhow;
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+/// Reads a session token from stdin and saves it where the config will
+/// find it.
+pub fn login() -> Result<(), anyhow::Error> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        eprint!("Paste the `session` cookie from adventofcode.com: ");
+        io::stderr().flush()?;
+    }
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+
+    let token = parse_token(&line)?;
+    let dir = user_dir().ok_or_else(|| anyhow!("Cannot find a config directory; set HOME"))?;
+    let path = save_session(&dir, token)?;
+    println!("Saved the session token to {}", path.display());
+    println!("Run `aoc auth status` to check it.");
+
+    Ok(())
+}
+
+/// Checks the configured session against the site.
+pub fn status(client: &impl AocClient, config: &Config) -> Result<(), anyhow::Error> {
+    let user = client.user(config.year.value)?;
+    println!(
+        "Logged in as {} (session from {})",
+        user, config.session.source
+    );
+    Ok(())
+}
+
+/// Accepts the cookie value on its own or pasted as `session=...`.
+fn parse_token(line: &str) -> Result<&str, anyhow::Error> {
+    let token = line.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    if token.is_empty() {
+        return Err(anyhow!("No session token given"));
+    }
+    if !token.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!(
+            "A session token is a string of hex digits; check the cookie was copied whole"
+        ));
+    }
+    Ok(token)
+}
+
+/// Writes the token to `dir`, readable only by the current user.
+fn save_session(dir: &Path, token: &str) -> Result<PathBuf, anyhow::Error> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(SESSION_FILE);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode only applies to new files, so tighten an existing one.
+        if path.exists() {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    let mut file = options
+        .open(&path)
+        .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    writeln!(file, "{}", token)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{config::Layer, test_support::TempDir};
+
+    #[test]
+    fn test_parse_token() {
+        assert_eq!(parse_token("53616c74\n").unwrap(), "53616c74");
+        assert_eq!(parse_token("session=53616c74").unwrap(), "53616c74");
+        assert!(parse_token("  \n").is_err());
+        assert!(parse_token("session=5361 6c74").is_err());
+    }
+
+    #[test]
+    fn test_save_session() {
+        let dir = TempDir::new("auth");
+        let path = save_session(dir.path(), "53616c74").unwrap();
+
+        let layer = Layer::from_session_file(&path).unwrap();
+        assert_eq!(layer.session.as_deref(), Some("53616c74"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
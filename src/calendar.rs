@@ -0,0 +1,183 @@
+//! The event schedule, so requests for puzzles that are not out yet can be
+//! refused without asking the site.
+//!
+//! Puzzles unlock at midnight US-Eastern (UTC-5 in December) each day from
+//! December 1st: 25 of them up to 2024, 12 from 2025.
+
+use crate::timer::format_countdown;
+use anyhow::anyhow;
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The first event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Midnight in US-Eastern is 05:00 UTC.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Clock tells the time and waits, so tests can control both.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// FakeClock only moves when something sleeps on it.
+#[cfg(test)]
+pub struct FakeClock(std::cell::Cell<SystemTime>);
+
+#[cfg(test)]
+impl FakeClock {
+    pub fn at(now: SystemTime) -> FakeClock {
+        FakeClock(std::cell::Cell::new(now))
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.0.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.0.set(self.0.get() + duration)
+    }
+}
+
+/// The number of puzzles in an event.
+pub fn puzzle_count(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// When a day's puzzle unlocks.
+pub fn unlock_time(year: u16, day: u8) -> Result<SystemTime, anyhow::Error> {
+    if year < FIRST_YEAR {
+        return Err(anyhow!("Advent of Code started in {}", FIRST_YEAR));
+    }
+    if day == 0 || day > puzzle_count(year) {
+        return Err(anyhow!(
+            "{} only has puzzles for days 1 to {}",
+            year,
+            puzzle_count(year)
+        ));
+    }
+
+    let days = days_from_civil(year.into(), 12, day.into());
+    Ok(UNIX_EPOCH + Duration::from_secs(days * 86_400 + UNLOCK_HOUR_UTC * 3_600))
+}
+
+/// How long until a day's puzzle unlocks, or `None` if it already has.
+pub fn time_until_unlock(
+    clock: &impl Clock,
+    year: u16,
+    day: u8,
+) -> Result<Option<Duration>, anyhow::Error> {
+    let unlock = unlock_time(year, day)?;
+    Ok(unlock
+        .duration_since(clock.now())
+        .ok()
+        .filter(|d| !d.is_zero()))
+}
+
+/// Fails with a countdown if a day's puzzle has not unlocked yet.
+pub fn ensure_unlocked(clock: &impl Clock, year: u16, day: u8) -> Result<(), anyhow::Error> {
+    match time_until_unlock(clock, year, day)? {
+        Some(remaining) => Err(anyhow!(
+            "Day {} of {} unlocks in {}; use `aoc wait {}` to pull it as soon as it does",
+            day,
+            year,
+            format_countdown(remaining),
+            day
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Sleeps until a day's puzzle unlocks, calling `tick` with the time left
+/// about once a second.
+pub fn wait_until_unlocked(
+    clock: &impl Clock,
+    year: u16,
+    day: u8,
+    mut tick: impl FnMut(Duration),
+) -> Result<(), anyhow::Error> {
+    while let Some(remaining) = time_until_unlock(clock, year, day)? {
+        tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+    Ok(())
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    // Howard Hinnant's algorithm, counting years from March so the leap day
+    // comes last.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn at(secs: u64) -> FakeClock {
+        FakeClock::at(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let secs = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(secs(unlock_time(2025, 1).unwrap()), 1_764_565_200);
+        assert_eq!(secs(unlock_time(2015, 25).unwrap()), 1_451_019_600);
+        assert!(unlock_time(2025, 13).is_err());
+        assert!(unlock_time(2014, 1).is_err());
+    }
+
+    #[test]
+    fn test_ensure_unlocked() {
+        let unlock = 1_764_565_200;
+        let err = ensure_unlocked(&at(unlock - 3_725), 2025, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1 of 2025 unlocks in 1h 02m 05s; use `aoc wait 1` to pull it as soon as it does"
+        );
+        assert!(ensure_unlocked(&at(unlock), 2025, 1).is_ok());
+    }
+
+    #[test]
+    fn test_wait_until_unlocked() {
+        let unlock = UNIX_EPOCH + Duration::from_secs(1_764_565_200);
+        let clock = FakeClock::at(unlock - Duration::from_millis(2_500));
+        let mut ticks = Vec::new();
+
+        wait_until_unlocked(&clock, 2025, 1, |remaining| ticks.push(remaining)).unwrap();
+
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(
+            ticks,
+            [
+                Duration::from_millis(2_500),
+                Duration::from_millis(1_500),
+                Duration::from_millis(500)
+            ]
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Talks to the Advent of Code website.
+
+use crate::config::Config;
+use anyhow::anyhow;
+use bytes::Bytes;
+use reqwest::{
+    StatusCode,
+    blocking::{RequestBuilder, Response},
+    header::{CONTENT_TYPE, LOCATION},
+    redirect::Policy,
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the site, as its maintainers ask automated tools
+/// to do.
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The error for any request the site answers as if we were logged out.
+pub fn login_required() -> anyhow::Error {
+    anyhow!(
+        "The session token is missing or has expired. Log in to adventofcode.com, copy the \
+         `session` cookie and save it with `aoc auth login`."
+    )
+}
+
+/// AocClient is everything the commands need from the site, so tests can
+/// stand in for it.
+pub trait AocClient {
+    /// Fetches the puzzle input for a day.
+    fn input(&self, year: u16, day: u8) -> Result<Bytes, anyhow::Error>;
+
+    /// Fetches the name the session is logged in as.
+    fn user(&self, year: u16) -> Result<String, anyhow::Error>;
+
+    /// Fetches a private leaderboard as JSON.
+    fn leaderboard(&self, year: u16, id: u64) -> Result<Bytes, anyhow::Error>;
+
+    /// Fetches the page of the user's own times and ranks.
+    fn self_stats(&self, year: u16) -> Result<String, anyhow::Error>;
+
+    /// Fetches the page describing a day's puzzle.
+    fn description(&self, year: u16, day: u8) -> Result<String, anyhow::Error>;
+
+    /// Submits an answer to one part of a day's puzzle, returning the page
+    /// the site replies with.
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, anyhow::Error>;
+}
+
+/// Builds a client for the configured site and session.
+pub fn connect(config: &Config) -> Result<HttpClient, anyhow::Error> {
+    let session = config.session.value.as_deref().ok_or_else(login_required)?;
+    HttpClient::new(&config.base_url.value, session)
+}
+
+/// HttpClient makes requests on behalf of the user with the given session.
+pub struct HttpClient {
+    base_url: String,
+    session: String,
+    http: reqwest::blocking::Client,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str) -> Result<HttpClient, anyhow::Error> {
+        // Redirects are not followed: the site redirects to its login page
+        // when the session is bad, and that page is not what we asked for.
+        let http = reqwest::blocking::Client::builder()
+            .redirect(Policy::none())
+            .user_agent(USER_AGENT)
+            .build()?;
+        Ok(HttpClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            http,
+        })
+    }
+
+    fn get(&self, path: &str) -> Result<Bytes, anyhow::Error> {
+        let url = format!("{}{}", self.base_url, path);
+        log::debug!("GET {}", url);
+        self.send(self.http.get(&url), &url)
+    }
+
+    fn post_form(&self, path: &str, form: String) -> Result<Bytes, anyhow::Error> {
+        let url = format!("{}{}", self.base_url, path);
+        log::debug!("POST {}", url);
+        let request = self
+            .http
+            .post(&url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(form);
+        self.send(request, &url)
+    }
+
+    fn send(&self, request: RequestBuilder, url: &str) -> Result<Bytes, anyhow::Error> {
+        let response = request
+            .header("Cookie", format!("session={}", self.session))
+            .send()?;
+        check_login(response, url)
+    }
+}
+
+impl AocClient for HttpClient {
+    fn input(&self, year: u16, day: u8) -> Result<Bytes, anyhow::Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    fn user(&self, year: u16) -> Result<String, anyhow::Error> {
+        let page = self.get(&format!("/{}", year))?;
+        parse_user(&String::from_utf8_lossy(&page)).ok_or_else(login_required)
+    }
+
+    fn leaderboard(&self, year: u16, id: u64) -> Result<Bytes, anyhow::Error> {
+        self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+    }
+
+    fn self_stats(&self, year: u16) -> Result<String, anyhow::Error> {
+        let page = self.get(&format!("/{}/leaderboard/self", year))?;
+        Ok(String::from_utf8_lossy(&page).into_owned())
+    }
+
+    fn description(&self, year: u16, day: u8) -> Result<String, anyhow::Error> {
+        let page = self.get(&format!("/{}/day/{}", year, day))?;
+        Ok(String::from_utf8_lossy(&page).into_owned())
+    }
+
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, anyhow::Error> {
+        let form = format!("level={}&answer={}", part, form_encode(answer));
+        let page = self.post_form(&format!("/{}/day/{}/answer", year, day), form)?;
+        Ok(String::from_utf8_lossy(&page).into_owned())
+    }
+}
+
+/// Escapes a form value, leaving only the unreserved characters as they are.
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(b).to_string()
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Turns any sign of a login page into [`login_required`], and any other
+/// failure into an error naming the URL.
+fn check_login(response: Response, url: &str) -> Result<Bytes, anyhow::Error> {
+    let status = response.status();
+    if status.is_redirection() {
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .unwrap_or_default();
+        if location.contains("/auth/login") {
+            return Err(login_required());
+        }
+        return Err(anyhow!("{} redirected to {:?}", url, location));
+    }
+
+    let body = response.bytes()?;
+    if looks_logged_out(&body) {
+        return Err(login_required());
+    }
+    match status {
+        StatusCode::NOT_FOUND => Err(anyhow!("{} was not found; is it unlocked yet?", url)),
+        status if !status.is_success() => Err(anyhow!("{} returned {}", url, status)),
+        _ => Ok(body),
+    }
+}
+
+/// The site answers requests for inputs without a valid session with a
+/// "Please log in" message, sometimes with a 200 status.
+fn looks_logged_out(body: &[u8]) -> bool {
+    let body = String::from_utf8_lossy(body);
+    body.contains("Please log in") || body.contains("Puzzle inputs differ by user")
+}
+
+/// Finds the user name in the header of a page, which is only there when
+/// logged in.
+fn parse_user(html: &str) -> Option<String> {
+    let start = html.find(r#"<div class="user">"#)? + r#"<div class="user">"#.len();
+    let name = html[start..].split('<').next()?.trim();
+    (!name.is_empty()).then(|| name.replace("&amp;", "&"))
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use std::{
+        cell::Cell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    /// FakeClient answers with whatever it was given and counts the requests
+    /// it is sent. Anything it was not given is refused as if logged out.
+    #[derive(Default)]
+    pub struct FakeClient {
+        pub input: Option<&'static str>,
+        pub user: Option<&'static str>,
+        pub leaderboard: Option<&'static str>,
+        pub self_stats: Option<&'static str>,
+        pub description: Option<&'static str>,
+        /// The page returned for every answer submitted.
+        pub reply: Option<&'static str>,
+        pub requests: Cell<usize>,
+    }
+
+    impl FakeClient {
+        fn answer(&self, response: Option<&'static str>) -> Result<&'static str, anyhow::Error> {
+            self.requests.set(self.requests.get() + 1);
+            response.ok_or_else(login_required)
+        }
+    }
+
+    impl AocClient for FakeClient {
+        fn input(&self, _year: u16, _day: u8) -> Result<Bytes, anyhow::Error> {
+            Ok(Bytes::from_static(self.answer(self.input)?.as_bytes()))
+        }
+
+        fn user(&self, _year: u16) -> Result<String, anyhow::Error> {
+            Ok(self.answer(self.user)?.to_string())
+        }
+
+        fn leaderboard(&self, _year: u16, _id: u64) -> Result<Bytes, anyhow::Error> {
+            Ok(Bytes::from_static(
+                self.answer(self.leaderboard)?.as_bytes(),
+            ))
+        }
+
+        fn self_stats(&self, _year: u16) -> Result<String, anyhow::Error> {
+            Ok(self.answer(self.self_stats)?.to_string())
+        }
+
+        fn description(&self, _year: u16, _day: u8) -> Result<String, anyhow::Error> {
+            Ok(self.answer(self.description)?.to_string())
+        }
+
+        fn submit(
+            &self,
+            _year: u16,
+            _day: u8,
+            _part: u8,
+            _answer: &str,
+        ) -> Result<String, anyhow::Error> {
+            Ok(self.answer(self.reply)?.to_string())
+        }
+    }
+
+    /// Serves `response` to a single request on a local port. Returns the
+    /// base URL and a receiver for the request line and headers.
+    pub fn serve(response: String) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            sender.send(request).unwrap();
+        });
+
+        (base_url, receiver)
+    }
+
+    pub fn response(status: &str, headers: &[&str], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n", status, body.len());
+        for header in headers {
+            response.push_str(header);
+            response.push_str("\r\n");
+        }
+        response.push_str("\r\n");
+        response.push_str(body);
+        response
+    }
+
+    #[test]
+    fn test_input_sends_the_session() {
+        let (base_url, request) = serve(response("200 OK", &[], "1\n2\n"));
+        let client = HttpClient::new(&base_url, "abc123").unwrap();
+
+        assert_eq!(client.input(2025, 1).unwrap(), "1\n2\n");
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(request.contains("cookie: session=abc123"));
+    }
+
+    #[test]
+    fn test_login_redirect_is_reported() {
+        let (base_url, _) = serve(response("302 Found", &["Location: /2025/auth/login"], ""));
+        let client = HttpClient::new(&base_url, "expired").unwrap();
+
+        let err = client.user(2025).unwrap_err();
+        assert!(err.to_string().contains("aoc auth login"));
+    }
+
+    #[test]
+    fn test_login_page_is_not_saved_as_input() {
+        let (base_url, _) = serve(response(
+            "400 Bad Request",
+            &[],
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        ));
+        let client = HttpClient::new(&base_url, "expired").unwrap();
+
+        let err = client.input(2025, 1).unwrap_err();
+        assert!(err.to_string().contains("aoc auth login"));
+    }
+
+    #[test]
+    fn test_user() {
+        let page = r#"<header><div class="user">Ada &amp; Co <span class="star-count">12*</span></div></header>"#;
+        let (base_url, _) = serve(response("200 OK", &[], page));
+        let client = HttpClient::new(&base_url, "abc123").unwrap();
+
+        assert_eq!(client.user(2025).unwrap(), "Ada & Co");
+    }
+
+    #[test]
+    fn test_submit_posts_a_form() {
+        let (base_url, request) = serve(response("200 OK", &[], "<article></article>"));
+        let client = HttpClient::new(&base_url, "abc123").unwrap();
+
+        client.submit(2025, 1, 2, "-5").unwrap();
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.contains("content-type: application/x-www-form-urlencoded"));
+        assert!(request.contains("cookie: session=abc123"));
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("-1234"), "-1234");
+        assert_eq!(form_encode("a b&c=d"), "a%20b%26c%3Dd");
+    }
+
+    #[test]
+    fn test_parse_user_logged_out() {
+        assert_eq!(
+            parse_user(r#"<a href="/2025/auth/login">[Log In]</a>"#),
+            None
+        );
+    }
+}
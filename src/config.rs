@@ -0,0 +1,319 @@
+//! Layered configuration for the CLI.
+//!
+//! Values are taken from, in increasing priority: the built-in defaults,
+//! `aoc.toml` in the current directory, `~/.config/aoc/config.toml`, the
+//! session file written by `aoc auth login`, `AOC_*` environment variables
+//! and finally command line flags.
+
+use crate::{client::BASE_URL, output::Format};
+use anyhow::{Context, anyhow};
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The config file in the repository, relative to the current directory.
+pub const REPO_FILE: &str = "aoc.toml";
+
+/// Source is where an effective value came from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Source {
+    Default,
+    Repo,
+    User,
+    SessionFile,
+    Env,
+    Cli,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Source::Default => "default",
+            Source::Repo => REPO_FILE,
+            Source::User => "user config",
+            Source::SessionFile => "session file",
+            Source::Env => "environment",
+            Source::Cli => "command line",
+        })
+    }
+}
+
+/// Layer holds the values set by one source. Anything left unset falls
+/// through to the layer below.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Layer {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub input_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub format: Option<String>,
+    pub log: Option<String>,
+}
+
+impl Layer {
+    /// Reads a config file, treating a missing file as an empty layer.
+    pub fn from_file(path: &Path) -> Result<Layer, anyhow::Error> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Layer::default()),
+            Err(e) => Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Reads the token saved by `aoc auth login`, if there is one.
+    pub fn from_session_file(path: &Path) -> Result<Layer, anyhow::Error> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Layer {
+                session: Some(contents.trim().to_string()).filter(|s| !s.is_empty()),
+                ..Layer::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Layer::default()),
+            Err(e) => Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Reads the `AOC_*` variables through `var`. The bare `SESSION`
+    /// variable is still accepted for existing `.env` files.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<Layer, anyhow::Error> {
+        Ok(Layer {
+            session: var("AOC_SESSION").or_else(|| var("SESSION")),
+            base_url: var("AOC_BASE_URL"),
+            input_dir: var("AOC_INPUT_DIR").map(PathBuf::from),
+            year: var("AOC_YEAR")
+                .map(|year| {
+                    year.parse()
+                        .with_context(|| format!("Invalid AOC_YEAR {:?}", year))
+                })
+                .transpose()?,
+            format: var("AOC_FORMAT"),
+            log: var("AOC_LOG"),
+        })
+    }
+}
+
+/// Setting is an effective value and the layer it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    fn new(value: T) -> Self {
+        Setting {
+            value,
+            source: Source::Default,
+        }
+    }
+
+    fn set(&mut self, value: Option<T>, source: Source) {
+        if let Some(value) = value {
+            *self = Setting { value, source };
+        }
+    }
+}
+
+/// Config is the effective configuration after every layer is applied.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub session: Setting<Option<String>>,
+    /// The site to talk to; point it at `aoc mock-server` to work offline.
+    pub base_url: Setting<String>,
+    pub input_dir: Setting<PathBuf>,
+    pub year: Setting<u16>,
+    pub format: Setting<Format>,
+    pub log: Setting<Option<log::Level>>,
+}
+
+impl Config {
+    /// Builds the config from the files, the environment and `cli`.
+    pub fn load(default_year: u16, cli: Layer) -> Result<Config, anyhow::Error> {
+        let mut layers = vec![(Layer::from_file(Path::new(REPO_FILE))?, Source::Repo)];
+        if let Some(dir) = user_dir() {
+            layers.push((Layer::from_file(&dir.join("config.toml"))?, Source::User));
+            layers.push((
+                Layer::from_session_file(&dir.join(SESSION_FILE))?,
+                Source::SessionFile,
+            ));
+        }
+        layers.push((
+            Layer::from_env(|name| env::var(name).ok().filter(|value| !value.is_empty()))?,
+            Source::Env,
+        ));
+        layers.push((cli, Source::Cli));
+
+        Config::from_layers(default_year, layers)
+    }
+
+    /// Applies `layers` in order, each overriding those before it.
+    pub fn from_layers(
+        default_year: u16,
+        layers: Vec<(Layer, Source)>,
+    ) -> Result<Config, anyhow::Error> {
+        let mut config = Config {
+            session: Setting::new(None),
+            base_url: Setting::new(BASE_URL.to_string()),
+            input_dir: Setting::new(PathBuf::from("inputs")),
+            year: Setting::new(default_year),
+            format: Setting::new(Format::Text),
+            log: Setting::new(None),
+        };
+
+        for (layer, source) in layers {
+            config.session.set(layer.session.map(Some), source);
+            config.base_url.set(layer.base_url, source);
+            config.input_dir.set(layer.input_dir, source);
+            config.year.set(layer.year, source);
+            let format = layer
+                .format
+                .map(|format| {
+                    Format::from_str(&format, true)
+                        .map_err(|_| anyhow!("Unknown format {:?} from {}", format, source))
+                })
+                .transpose()?;
+            config.format.set(format, source);
+            let log = layer
+                .log
+                .map(|level| {
+                    log::Level::from_str(&level)
+                        .map_err(|_| anyhow!("Unknown log level {:?} from {}", level, source))
+                })
+                .transpose()?;
+            config.log.set(log.map(Some), source);
+        }
+
+        Ok(config)
+    }
+
+    /// Prints the effective values, one per line, with the session token
+    /// redacted.
+    pub fn show(&self) -> String {
+        let session = match &self.session.value {
+            Some(_) => "\"<redacted>\"".to_string(),
+            None => "(unset)".to_string(),
+        };
+        let format = self
+            .format
+            .value
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        let log = match self.log.value {
+            Some(level) => format!("{:?}", level.as_str().to_lowercase()),
+            None => "(unset)".to_string(),
+        };
+
+        [
+            ("session", session, self.session.source),
+            (
+                "base-url",
+                format!("{:?}", self.base_url.value),
+                self.base_url.source,
+            ),
+            (
+                "input-dir",
+                format!("{:?}", self.input_dir.value.display().to_string()),
+                self.input_dir.source,
+            ),
+            ("year", self.year.value.to_string(), self.year.source),
+            ("format", format!("{:?}", format), self.format.source),
+            ("log", log, self.log.source),
+        ]
+        .iter()
+        .map(|(key, value, source)| format!("{} = {}  # {}\n", key, value, source))
+        .collect()
+    }
+}
+
+/// The file `aoc auth login` stores the session token in, inside the user
+/// config directory.
+pub const SESSION_FILE: &str = "session";
+
+/// `$XDG_CACHE_HOME/aoc`, falling back to `~/.cache/aoc`.
+pub fn cache_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+    Some(base.join("aoc"))
+}
+
+/// `$XDG_CONFIG_HOME/aoc`, falling back to `~/.config/aoc`.
+pub fn user_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("aoc"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn layer(toml: &str) -> Layer {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_later_layers_override_earlier_ones() {
+        let config = Config::from_layers(
+            2025,
+            vec![
+                (
+                    layer("input-dir = \"repo-inputs\"\nyear = 2023\nformat = \"csv\""),
+                    Source::Repo,
+                ),
+                (layer("year = 2024\nlog = \"debug\""), Source::User),
+                (
+                    Layer::from_env(|name| (name == "SESSION").then(|| "abc".to_string())).unwrap(),
+                    Source::Env,
+                ),
+                (
+                    Layer {
+                        format: Some("json".to_string()),
+                        ..Layer::default()
+                    },
+                    Source::Cli,
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(config.session.value.as_deref(), Some("abc"));
+        assert_eq!(config.session.source, Source::Env);
+        assert_eq!(config.input_dir.value, PathBuf::from("repo-inputs"));
+        assert_eq!(config.year.value, 2024);
+        assert_eq!(config.year.source, Source::User);
+        assert!(matches!(config.format.value, Format::Json));
+        assert_eq!(config.format.source, Source::Cli);
+        assert_eq!(config.log.value, Some(log::Level::Debug));
+    }
+
+    #[test]
+    fn test_show_redacts_the_session() {
+        let config = Config::from_layers(
+            2025,
+            vec![(layer("session = \"53616c7465645f5f\""), Source::User)],
+        )
+        .unwrap();
+        let shown = config.show();
+
+        assert!(!shown.contains("53616c7465645f5f"));
+        assert!(shown.contains("session = \"<redacted>\"  # user config"));
+        assert!(shown.contains("year = 2025  # default"));
+    }
+
+    #[test]
+    fn test_bad_values_are_errors() {
+        assert!(toml::from_str::<Layer>("colour = \"red\"").is_err());
+        assert!(
+            Config::from_layers(2025, vec![(layer("format = \"xml\""), Source::Repo)]).is_err()
+        );
+        assert!(Layer::from_env(|name| (name == "AOC_YEAR").then(|| "soon".to_string())).is_err());
+    }
+}
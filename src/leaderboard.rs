@@ -0,0 +1,270 @@
+//! `aoc leaderboard`: a private leaderboard, cached on disk for as long as
+//! the site asks.
+
+use crate::{
+    calendar::{Clock, puzzle_count, unlock_time},
+    client::AocClient,
+};
+use anyhow::Context;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs,
+    path::Path,
+    time::{Duration, UNIX_EPOCH},
+};
+
+/// The site asks that leaderboards are fetched at most once every 15
+/// minutes.
+pub const CACHE_FOR: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Day, then part, to when the star was earned.
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    /// The name shown on the site, which for anonymous members is their id.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the member earned a star, in seconds since the epoch.
+    pub fn star_time(&self, day: u8, part: u8) -> Option<u64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+
+    fn stars_on(&self, day: u8) -> usize {
+        (1..=2)
+            .filter(|&part| self.star_time(day, part).is_some())
+            .count()
+    }
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, anyhow::Error> {
+    serde_json::from_str(json).context("Failed to parse the leaderboard")
+}
+
+/// Returns the leaderboard JSON, from `cache_dir` if it was fetched within
+/// [`CACHE_FOR`], otherwise from the site.
+pub fn fetch(
+    client: &impl AocClient,
+    clock: &impl Clock,
+    cache_dir: &Path,
+    year: u16,
+    id: u64,
+) -> Result<String, anyhow::Error> {
+    let path = cache_dir.join(format!("leaderboard-{}-{}.json", year, id));
+
+    let age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| clock.now().duration_since(modified).ok());
+    if let Some(age) = age.filter(|&age| age < CACHE_FOR) {
+        log::debug!("Using {} from {}s ago", path.display(), age.as_secs());
+        return Ok(fs::read_to_string(&path)?);
+    }
+
+    let json = String::from_utf8(client.leaderboard(year, id)?.to_vec())?;
+    // Check it parses before caching it, so a bad response is not served
+    // for the next 15 minutes.
+    parse(&json)?;
+    fs::create_dir_all(cache_dir)?;
+    fs::write(&path, &json)?;
+
+    Ok(json)
+}
+
+/// Members by local score, best first.
+fn ranked(board: &Leaderboard) -> Vec<&Member> {
+    let mut members: Vec<&Member> = board.members.values().collect();
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then_with(|| a.display_name().cmp(&b.display_name()))
+    });
+    members
+}
+
+/// Renders the members with their score, stars and a column per day: `*`
+/// for both stars, `+` for only the first and `.` for none.
+pub fn render_table(board: &Leaderboard, year: u16) -> String {
+    let days = 1..=puzzle_count(year);
+    // Rank, score and stars take up the first 17 columns of every row.
+    let prefix = " ".repeat(17);
+
+    let mut out = String::new();
+    // Day numbers read downwards, tens above units, as on the site.
+    let tens: String = days
+        .clone()
+        .map(|day| match day / 10 {
+            0 => ' ',
+            tens => char::from(b'0' + tens),
+        })
+        .collect();
+    let units: String = days
+        .clone()
+        .map(|day| char::from(b'0' + day % 10))
+        .collect();
+    writeln!(out, "{}{}", prefix, tens.trim_end()).unwrap();
+    writeln!(out, "{:>4} {:>5} {:>5} {}", "", "Score", "Stars", units).unwrap();
+
+    for (rank, member) in ranked(board).iter().enumerate() {
+        let stars: String = days
+            .clone()
+            .map(|day| match member.stars_on(day) {
+                2 => '*',
+                1 => '+',
+                _ => '.',
+            })
+            .collect();
+        writeln!(
+            out,
+            "{:>3}) {:>5} {:>5} {} {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            stars,
+            member.display_name()
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+/// Renders how long after unlocking each member took to earn the day's
+/// stars, fastest to finish first.
+pub fn render_day(board: &Leaderboard, year: u16, day: u8) -> Result<String, anyhow::Error> {
+    let unlock = unlock_time(year, day)?
+        .duration_since(UNIX_EPOCH)?
+        .as_secs();
+    let elapsed = |member: &Member, part| {
+        member
+            .star_time(day, part)
+            .map(|time| time.saturating_sub(unlock))
+    };
+
+    let mut members: Vec<&Member> = board
+        .members
+        .values()
+        .filter(|member| member.stars_on(day) > 0)
+        .collect();
+    // `None` sorts first, so compare `Some` ahead of it by hand.
+    members.sort_by_key(|member| {
+        (
+            elapsed(member, 2).is_none(),
+            elapsed(member, 2),
+            elapsed(member, 1),
+            member.id,
+        )
+    });
+
+    let mut out = format!("Day {} of {}\n", day, year);
+    writeln!(out, "{:>4} {:>10} {:>10}", "", "Part 1", "Part 2")?;
+    for (rank, member) in members.iter().enumerate() {
+        writeln!(
+            out,
+            "{:>3}) {:>10} {:>10}  {}",
+            rank + 1,
+            format_elapsed(elapsed(member, 1)),
+            format_elapsed(elapsed(member, 2)),
+            member.display_name()
+        )?;
+    }
+    Ok(out)
+}
+
+/// Formats seconds as `HH:MM:SS`, letting the hours run past a day.
+fn format_elapsed(secs: Option<u64>) -> String {
+    match secs {
+        Some(secs) => format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{calendar::FakeClock, client::test::FakeClient, test_support::TempDir};
+    use std::time::SystemTime;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn test_render_table() {
+        let board = parse(FIXTURE).unwrap();
+        assert_eq!(
+            render_table(&board, 2025),
+            concat!(
+                "                          111\n",
+                "     Score Stars 123456789012\n",
+                "  1)    11     4 **.......... Ada Lovelace\n",
+                "  2)     9     3 *+.......... (anonymous user #202)\n",
+                "  3)     0     0 ............ Grace Hopper\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_day() {
+        let board = parse(FIXTURE).unwrap();
+        assert_eq!(
+            render_day(&board, 2025, 1).unwrap(),
+            concat!(
+                "Day 1 of 2025\n",
+                "         Part 1     Part 2\n",
+                "  1)   00:12:34   00:20:01  Ada Lovelace\n",
+                "  2)   00:08:20   25:00:00  (anonymous user #202)\n",
+            )
+        );
+        assert!(
+            render_day(&board, 2025, 2)
+                .unwrap()
+                .ends_with("  2)   00:01:40          -  (anonymous user #202)\n")
+        );
+    }
+
+    #[test]
+    fn test_fetch_caches_for_15_minutes() {
+        let temp = TempDir::new("leaderboard");
+        let dir = temp.path();
+        let client = FakeClient {
+            leaderboard: Some(FIXTURE),
+            ..FakeClient::default()
+        };
+        let clock = FakeClock::at(SystemTime::now());
+
+        fetch(&client, &clock, dir, 2025, 101).unwrap();
+        clock.sleep(Duration::from_secs(10 * 60));
+        let cached = fetch(&client, &clock, dir, 2025, 101).unwrap();
+        assert_eq!(client.requests.get(), 1);
+        assert_eq!(cached, FIXTURE);
+
+        clock.sleep(Duration::from_secs(10 * 60));
+        fetch(&client, &clock, dir, 2025, 101).unwrap();
+        assert_eq!(client.requests.get(), 2);
+    }
+}
@@ -0,0 +1,66 @@
+//! Advent of Code solutions, one module per event, and the tooling the
+//! `aoc` binary is built from: fetching inputs, running and timing
+//! solutions and reporting on them.
+
+pub mod alloc;
+pub mod auth;
+pub mod calendar;
+pub mod client;
+pub mod config;
+pub mod leaderboard;
+pub mod mock_server;
+pub mod output;
+pub mod parse;
+pub mod pull;
+pub mod puzzle;
+pub mod repl;
+pub mod runner;
+pub mod stats;
+pub mod submit;
+#[cfg(test)]
+mod test_support;
+pub mod timer;
+pub mod trace;
+pub mod y2025;
+
+use crate::{
+    puzzle::Parts,
+    runner::{Options, PartReport, unimplemented},
+    trace::TraceFormat,
+};
+use anyhow::anyhow;
+
+/// The most recent event, used when no year is configured.
+pub const CURRENT_YEAR: u16 = y2025::YEAR;
+
+/// Solves the requested parts of a day from any event.
+pub fn solve(year: u16, day: u8, parts: Parts, options: Options) -> Vec<PartReport> {
+    match year {
+        y2025::YEAR => y2025::solve(day, parts, options),
+        _ => unimplemented(year, day, parts),
+    }
+}
+
+/// Prints the state after every step of a day which supports it.
+pub fn trace(year: u16, day: u8, format: TraceFormat) -> Result<(), anyhow::Error> {
+    match year {
+        y2025::YEAR => y2025::trace(day, format),
+        _ => Err(anyhow!("There are no solutions for {}.", year)),
+    }
+}
+
+/// Shows how a day which supports it reached its answer.
+pub fn explain(year: u16, day: u8) -> Result<(), anyhow::Error> {
+    match year {
+        y2025::YEAR => y2025::explain(day),
+        _ => Err(anyhow!("There are no solutions for {}.", year)),
+    }
+}
+
+/// Explores a day's input interactively, for days which have a REPL.
+pub fn repl(year: u16, day: u8) -> Result<(), anyhow::Error> {
+    match year {
+        y2025::YEAR => y2025::repl(day),
+        _ => Err(anyhow!("There are no solutions for {}.", year)),
+    }
+}
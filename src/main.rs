@@ -1,25 +1,26 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod puzzle;
-mod timer;
-
 use anyhow::anyhow;
-use std::{env, fs};
+use std::{
+    io::{self, IsTerminal},
+    path::PathBuf,
+    time::Duration,
+};
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::puzzle::Parts;
-use reqwest::blocking::Client;
+use advent_2025::{
+    CURRENT_YEAR, auth,
+    calendar::{self, SystemClock},
+    client::{self, AocClient},
+    config::{self, Config, Layer},
+    leaderboard, mock_server,
+    output::{self, Format},
+    pull,
+    puzzle::{self, Parts},
+    runner::{self, Options, PartReport, Status},
+    stats, submit,
+    timer::{self, format_countdown},
+    trace::TraceFormat,
+};
 
 #[derive(Parser)]
 #[command(name = "aoc")]
@@ -31,21 +32,116 @@ struct Cli {
     /// Set the logging level
     #[arg(long, global = true, value_enum)]
     log: Option<log::Level>,
+
+    /// The event to work on, by default the most recent one
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+
+    /// The directory inputs are read from and pulled into
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+}
+
+impl Cli {
+    /// The flags which override values from the config files.
+    fn layer(&self) -> Layer {
+        let format = match &self.command {
+            Commands::Run { format, .. } => *format,
+            _ => None,
+        };
+        Layer {
+            session: None,
+            base_url: None,
+            input_dir: self.input_dir.clone(),
+            year: self.year,
+            format: format
+                .and_then(|format| format.to_possible_value())
+                .map(|value| value.get_name().to_string()),
+            log: self.log.map(|level| level.as_str().to_string()),
+        }
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Show a private leaderboard
+    Leaderboard {
+        /// The leaderboard's id, the number at the end of its URL
+        id: u64,
+
+        /// Print the leaderboard JSON as the site returned it
+        #[arg(long)]
+        json: bool,
+
+        /// Show how long each member took on one day
+        #[arg(long, conflicts_with = "json")]
+        day: Option<u8>,
+    },
+    /// Compare your stars and times on the site with the local solutions
+    Stats,
+    /// Serve inputs, descriptions and answers from a directory, standing in
+    /// for the site. Point `AOC_BASE_URL` at it.
+    MockServer {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        addr: String,
+
+        /// The directory to serve, by default the input directory
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
     /// Pull the input for a specific day
     Pull {
         /// The day to pull (1-25)
         #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
         day: u8,
     },
-    /// Run the solution for a specific day
-    Run {
-        /// The day to run (1-25)
+    /// Print the description of a specific day's puzzle
+    Fetch {
+        /// The day to fetch (1-25)
         #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
         day: u8,
+    },
+    /// Submit an answer to one part of a day's puzzle
+    Submit {
+        /// The day the answer is for (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part the answer is for (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The answer to submit
+        answer: String,
+    },
+    /// Manage the session token used to talk to the site
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
+    /// Inspect the configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+    /// Wait for a day's puzzle to unlock, then pull its input
+    Wait {
+        /// The day to wait for (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Explore a day's input interactively
+    Repl {
+        /// The day to explore (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Run the solutions for one or more days
+    Run {
+        /// The days to run (1-25)
+        #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
 
         /// Run only the first part
         #[arg(long, conflicts_with = "second")]
@@ -54,62 +150,254 @@ enum Commands {
         /// Run only the second part
         #[arg(long, conflicts_with = "first")]
         second: bool,
+
+        /// Format of the answers [default: text]
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+
+        /// Run both parts of a day, and the days themselves, on separate threads
+        #[arg(long)]
+        parallel: bool,
+
+        /// Report time and allocations for each part next to its answer
+        #[arg(long, conflicts_with = "parallel")]
+        stats: bool,
+
+        /// Fail any part which takes longer than this, e.g. `500ms` or `10s`
+        #[arg(long, value_parser = timer::parse_duration)]
+        timeout: Option<Duration>,
+
+        /// Print the state after every step instead of the answers
+        #[arg(long)]
+        trace: bool,
+
+        /// Show how the answer was reached instead of the answers
+        #[arg(long, conflicts_with = "trace")]
+        explain: bool,
+
+        /// Format of the trace output
+        #[arg(long, value_enum, default_value_t = TraceFormat::Text, requires = "trace")]
+        trace_format: TraceFormat,
     },
 }
 
+#[derive(Subcommand)]
+enum AuthCommands {
+    /// Save a session token read from stdin
+    Login,
+    /// Check the session token and show who it belongs to
+    Status,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Print the effective configuration and where each value came from
+    Show,
+}
+
 fn main() -> Result<(), anyhow::Error> {
-    dotenvy::dotenv()?;
+    // The `.env` file is optional; only a malformed one is an error.
+    if let Err(e) = dotenvy::dotenv()
+        && !e.not_found()
+    {
+        return Err(e.into());
+    }
 
     let cli = Cli::parse();
+    let config = Config::load(CURRENT_YEAR, cli.layer())?;
 
-    if let Some(level) = cli.log {
+    if let Some(level) = config.log.value {
         simple_logger::init_with_level(level)?;
     }
+    puzzle::set_input_dir(config.input_dir.value.clone());
 
+    let year = config.year.value;
     match cli.command {
-        Commands::Pull { day } => pull(day),
-        Commands::Run { day, first, second } => match (first, second) {
-            (false, false) => run(day, Parts::Both),
-            (true, false) => run(day, Parts::First),
-            (false, true) => run(day, Parts::Second),
-            (true, true) => unreachable!("prevented by Clap conflicts_with"),
-        },
+        Commands::Auth {
+            command: AuthCommands::Login,
+        } => auth::login(),
+        Commands::Auth {
+            command: AuthCommands::Status,
+        } => auth::status(&client::connect(&config)?, &config),
+        Commands::Config {
+            command: ConfigCommands::Show,
+        } => {
+            print!("{}", config.show());
+            Ok(())
+        }
+        Commands::Leaderboard { id, json, day } => {
+            let cache_dir = config::cache_dir()
+                .ok_or_else(|| anyhow!("Cannot find a cache directory; set HOME"))?;
+            let raw = leaderboard::fetch(
+                &client::connect(&config)?,
+                &SystemClock,
+                &cache_dir,
+                year,
+                id,
+            )?;
+            if json {
+                println!("{}", raw);
+                return Ok(());
+            }
+            let board = leaderboard::parse(&raw)?;
+            match day {
+                Some(day) => print!("{}", leaderboard::render_day(&board, year, day)?),
+                None => print!("{}", leaderboard::render_table(&board, year)),
+            }
+            Ok(())
+        }
+        Commands::Stats => {
+            let page = client::connect(&config)?.self_stats(year)?;
+            let site = stats::parse_self(&page)?;
+            let local: Vec<stats::LocalDay> = (1..=calendar::puzzle_count(year))
+                .filter(|&day| puzzle::input_path(year, day).exists())
+                .map(|day| {
+                    Ok(stats::LocalDay {
+                        day,
+                        reports: advent_2025::solve(year, day, Parts::Both, Options::default()),
+                        expected: puzzle::expected_answers(year, day)?,
+                    })
+                })
+                .collect::<Result<_, anyhow::Error>>()?;
+            print!("{}", stats::render(&site, &local));
+            Ok(())
+        }
+        Commands::MockServer { addr, dir } => {
+            let dir = dir.unwrap_or_else(|| config.input_dir.value.clone());
+            let server = mock_server::MockServer::bind(&addr, &dir)?;
+            let addr = server.local_addr()?;
+            eprintln!("Serving {} on http://{}", dir.display(), addr);
+            eprintln!("Use it with AOC_BASE_URL=http://{}", addr);
+            server.serve()
+        }
+        Commands::Pull { day } => pull(&config, year, day),
+        Commands::Fetch { day } => {
+            let description = pull::fetch(&client::connect(&config)?, &SystemClock, year, day)?;
+            println!("{}", description);
+            Ok(())
+        }
+        Commands::Submit { day, part, answer } => {
+            let reply = submit::submit(
+                &client::connect(&config)?,
+                &SystemClock,
+                year,
+                day,
+                part,
+                &answer,
+            )?;
+            println!("{}", reply);
+            Ok(())
+        }
+        Commands::Wait { day } => {
+            // Connect first so a missing session is reported before the wait.
+            let client = client::connect(&config)?;
+            wait(year, day)?;
+            let path = pull::pull(&client, &SystemClock, &config.input_dir.value, year, day)?;
+            println!("Saved {}", path.display());
+            Ok(())
+        }
+        Commands::Repl { day } => advent_2025::repl(year, day),
+        Commands::Run {
+            days,
+            trace: true,
+            trace_format,
+            ..
+        } => advent_2025::trace(year, single_day(&days)?, trace_format),
+        Commands::Run {
+            days,
+            explain: true,
+            ..
+        } => advent_2025::explain(year, single_day(&days)?),
+        Commands::Run {
+            days,
+            first,
+            second,
+            parallel,
+            stats,
+            timeout,
+            ..
+        } => {
+            let options = Options { parallel, timeout };
+            let format = config.format.value;
+            match (first, second) {
+                (false, false) => run(year, &days, Parts::Both, format, stats, options),
+                (true, false) => run(year, &days, Parts::First, format, stats, options),
+                (false, true) => run(year, &days, Parts::Second, format, stats, options),
+                (true, true) => unreachable!("prevented by Clap conflicts_with"),
+            }
+        }
     }
 }
 
-fn pull(day: u8) -> Result<(), anyhow::Error> {
-    let url = format!("https://adventofcode.com/2025/day/{}/input", day);
-
-    let client = Client::new();
-
-    let session = env::var("SESSION")?;
-
-    let response = client
-        .get(&url)
-        .header("Cookie", format!("session={}", session))
-        .send()?
-        .error_for_status()?;
+fn pull(config: &Config, year: u16, day: u8) -> Result<(), anyhow::Error> {
+    let path = pull::pull(
+        &client::connect(config)?,
+        &SystemClock,
+        &config.input_dir.value,
+        year,
+        day,
+    )?;
+    log::info!("Saved {}", path.display());
+    Ok(())
+}
 
-    fs::write(format!("./inputs/day_{:02}.txt", day), response.bytes()?)
-        .map_err(|e| anyhow!(e.to_string()))
+/// Counts down on stderr until the puzzle unlocks.
+fn wait(year: u16, day: u8) -> Result<(), anyhow::Error> {
+    let interactive = io::stderr().is_terminal();
+    calendar::wait_until_unlocked(&SystemClock, year, day, |remaining| {
+        if interactive {
+            eprint!(
+                "\rDay {} of {} unlocks in {}\x1b[K",
+                day,
+                year,
+                format_countdown(remaining)
+            );
+        }
+    })?;
+    if interactive {
+        eprintln!();
+    }
+    Ok(())
 }
 
-fn run(day: u8, parts: Parts) -> Result<(), anyhow::Error> {
-    match day {
-        1 => day_01::run(parts),
-        2 => day_02::run(parts),
-        3 => day_03::run(parts),
-        4 => day_04::run(parts),
-        5 => day_05::run(parts),
-        6 => day_06::run(parts),
-        7 => day_07::run(parts),
-        8 => day_08::run(parts),
-        9 => day_09::run(parts),
-        10 => day_10::run(parts),
-        11 => day_11::run(parts),
-        12 => day_12::run(parts),
-        _ => return Err(anyhow!("Invalid day. Valid days are 1-12 inclusive.")),
+fn run(
+    year: u16,
+    days: &[u8],
+    parts: Parts,
+    format: Format,
+    stats: bool,
+    options: Options,
+) -> Result<(), anyhow::Error> {
+    let reports: Vec<PartReport> = if options.parallel {
+        runner::parallel_map(days, |&day| advent_2025::solve(year, day, parts, options))
+            .into_iter()
+            .flatten()
+            .collect()
+    } else {
+        days.iter()
+            .flat_map(|&day| advent_2025::solve(year, day, parts, options))
+            .collect()
     };
 
+    output::print(&reports, format, stats)?;
+
+    let mut failed: Vec<u8> = reports
+        .iter()
+        .filter(|report| report.status == Status::Error)
+        .map(|report| report.day)
+        .collect();
+    failed.dedup();
+    if !failed.is_empty() {
+        return Err(anyhow!("Failed days: {:?}", failed));
+    }
+
     Ok(())
 }
+
+/// Trace and explain print a lot, so they only make sense for one day.
+fn single_day(days: &[u8]) -> Result<u8, anyhow::Error> {
+    match days {
+        [day] => Ok(*day),
+        _ => Err(anyhow!("--trace and --explain run a single day")),
+    }
+}
@@ -0,0 +1,429 @@
+//! `aoc mock-server`: a local stand-in for the site, for demos, CI and
+//! working offline.
+//!
+//! Files are served from a directory laid out like the inputs directory:
+//! `<year>/day_XX.txt` is the input, `<year>/day_XX.html` the description
+//! and `<year>/day_XX.answers` the answers to parts one and two, one per
+//! line. `<year>/leaderboard_<id>.json` is served as a private leaderboard
+//! and `<year>/leaderboard_self.html` as the user's own stats.
+//! Any session cookie is accepted; requests without one are treated
+//! as logged out.
+
+use anyhow::anyhow;
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    thread,
+};
+
+pub const USER: &str = "mock user";
+
+/// The largest request body read. Answers are short, so anything bigger is
+/// refused rather than allocated.
+const MAX_BODY: usize = 64 * 1024;
+
+pub struct MockServer {
+    listener: TcpListener,
+    dir: PathBuf,
+}
+
+impl MockServer {
+    pub fn bind(addr: &str, dir: &Path) -> Result<MockServer, anyhow::Error> {
+        let listener =
+            TcpListener::bind(addr).map_err(|e| anyhow!("Failed to bind {}: {}", addr, e))?;
+        Ok(MockServer {
+            listener,
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, anyhow::Error> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Answers requests until the process is stopped, one thread each.
+    pub fn serve(self) -> Result<(), anyhow::Error> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let dir = self.dir.clone();
+            thread::spawn(move || {
+                if let Err(e) = handle(stream, &dir) {
+                    log::warn!("Mock server request failed: {:#}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    session: Option<String>,
+    body: String,
+}
+
+struct Reply {
+    status: &'static str,
+    location: Option<String>,
+    body: String,
+}
+
+impl Reply {
+    fn ok(body: String) -> Reply {
+        Reply {
+            status: "200 OK",
+            location: None,
+            body,
+        }
+    }
+
+    fn status(status: &'static str, body: &str) -> Reply {
+        Reply {
+            status,
+            location: None,
+            body: body.to_string(),
+        }
+    }
+
+    fn redirect(location: String) -> Reply {
+        Reply {
+            status: "302 Found",
+            location: Some(location),
+            body: String::new(),
+        }
+    }
+}
+
+fn handle(stream: TcpStream, dir: &Path) -> Result<(), anyhow::Error> {
+    let request = read_request(&stream)?;
+    let reply = route(dir, &request);
+    log::info!("{} {} -> {}", request.method, request.path, reply.status);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n",
+        reply.status,
+        reply.body.len()
+    )?;
+    if let Some(location) = reply.location {
+        write!(stream, "Location: {}\r\n", location)?;
+    }
+    write!(stream, "\r\n{}", reply.body)?;
+    Ok(stream.flush()?)
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request, anyhow::Error> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(anyhow!("Malformed request line {:?}", line));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut session = None;
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line == "\r\n" {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.parse()?,
+            "cookie" => {
+                session = value
+                    .split(';')
+                    .filter_map(|cookie| cookie.trim().strip_prefix("session="))
+                    .find(|session| !session.is_empty())
+                    .map(str::to_string)
+            }
+            _ => {}
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(anyhow!(
+            "Request body of {} bytes is over the {} byte limit",
+            content_length,
+            MAX_BODY
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        session,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn route(dir: &Path, request: &Request) -> Reply {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let logged_in = request.session.is_some();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", [year]) => Reply::ok(page(year, logged_in, "")),
+        ("GET", [year, "day", day]) => match read(dir, year, day, "html") {
+            Some(description) => Reply::ok(page(year, logged_in, &description)),
+            None => Reply::status("404 Not Found", "404 Not Found"),
+        },
+        ("GET", [_, "day", _, "input"]) if !logged_in => Reply::status(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        ),
+        ("GET", [year, "day", day, "input"]) => match read(dir, year, day, "txt") {
+            Some(input) => Reply::ok(input),
+            None => Reply::status("404 Not Found", "404 Not Found"),
+        },
+        ("GET", [year, "leaderboard", "self"]) if !logged_in => {
+            Reply::redirect(format!("/{}/auth/login", year))
+        }
+        ("GET", [year, "leaderboard", "self"]) => {
+            let page = year.parse::<u16>().ok().and_then(|year| {
+                let path = dir.join(year.to_string()).join("leaderboard_self.html");
+                fs::read_to_string(path).ok()
+            });
+            match page {
+                Some(page) => Reply::ok(page),
+                None => Reply::status("404 Not Found", "404 Not Found"),
+            }
+        }
+        ("GET", [year, "leaderboard", "private", "view", _]) if !logged_in => {
+            Reply::redirect(format!("/{}/auth/login", year))
+        }
+        ("GET", [year, "leaderboard", "private", "view", file]) => {
+            let board = file.strip_suffix(".json").and_then(|id| {
+                let (year, id): (u16, u64) = (year.parse().ok()?, id.parse().ok()?);
+                let path = dir.join(year.to_string());
+                fs::read_to_string(path.join(format!("leaderboard_{}.json", id))).ok()
+            });
+            match board {
+                Some(board) => Reply::ok(board),
+                None => Reply::status("404 Not Found", "404 Not Found"),
+            }
+        }
+        ("POST", [year, "day", _, "answer"]) if !logged_in => {
+            Reply::redirect(format!("/{}/auth/login", year))
+        }
+        ("POST", [year, "day", day, "answer"]) => {
+            let message = check_answer(dir, year, day, &request.body);
+            Reply::ok(page(
+                year,
+                true,
+                &format!("<article><p>{}</p></article>", message),
+            ))
+        }
+        _ => Reply::status("404 Not Found", "404 Not Found"),
+    }
+}
+
+/// Wraps `main` in a page with the site's header, which names the user when
+/// logged in.
+fn page(year: &str, logged_in: bool, main: &str) -> String {
+    let user = if logged_in {
+        format!(r#"<div class="user">{}</div>"#, USER)
+    } else {
+        format!(r#"<a href="/{}/auth/login">[Log In]</a>"#, year)
+    };
+    format!(
+        "<!DOCTYPE html>\n<html><body><header>{}</header><main>{}</main></body></html>\n",
+        user, main
+    )
+}
+
+fn read(dir: &Path, year: &str, day: &str, extension: &str) -> Option<String> {
+    let day: u8 = day.parse().ok()?;
+    let year: u16 = year.parse().ok()?;
+    fs::read_to_string(
+        dir.join(year.to_string())
+            .join(format!("day_{:02}.{}", day, extension)),
+    )
+    .ok()
+}
+
+/// Answers a submitted form of `level=N&answer=X` the way the site words it.
+fn check_answer(dir: &Path, year: &str, day: &str, form: &str) -> &'static str {
+    let field = |name: &str| {
+        form.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| form_decode(key).as_deref() == Some(name))
+            .and_then(|(_, value)| form_decode(value))
+            .map(|value| value.trim().to_string())
+    };
+    let expected = field("level")
+        .and_then(|level| level.parse::<usize>().ok())
+        .and_then(|level| {
+            let answers = read(dir, year, day, "answers")?;
+            answers
+                .lines()
+                .nth(level.checked_sub(1)?)
+                .map(|answer| answer.trim().to_string())
+        });
+
+    match (expected, field("answer")) {
+        (Some(expected), Some(answer)) if expected == answer => "That's the right answer!",
+        (Some(_), Some(_)) => "That's not the right answer.",
+        _ => "You don't seem to be solving the right level.",
+    }
+}
+
+/// Decodes a form field, where `+` is a space and `%XX` a byte. `None` if
+/// an escape is malformed or the bytes are not UTF-8.
+fn form_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        client::{AocClient, HttpClient},
+        test_support::TempDir,
+    };
+
+    fn fixture(name: &str) -> TempDir {
+        let temp = TempDir::new(&format!("mock-{}", name));
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("2025")).unwrap();
+        fs::write(dir.join("2025/day_01.txt"), "L68\nR48\n").unwrap();
+        fs::write(dir.join("2025/day_01.answers"), "3\n6\n").unwrap();
+        fs::write(
+            dir.join("2025/day_01.html"),
+            "<article><h2>--- Day 1 ---</h2></article>",
+        )
+        .unwrap();
+        temp
+    }
+
+    fn request(method: &str, path: &str, session: Option<&str>, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            session: session.map(str::to_string),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_route() {
+        let temp = fixture("route");
+        let dir = temp.path();
+
+        let input = route(dir, &request("GET", "/2025/day/1/input", Some("abc"), ""));
+        assert_eq!(input.status, "200 OK");
+        assert_eq!(input.body, "L68\nR48\n");
+
+        let missing = route(dir, &request("GET", "/2025/day/2/input", Some("abc"), ""));
+        assert_eq!(missing.status, "404 Not Found");
+
+        let logged_out = route(dir, &request("GET", "/2025/day/1/input", None, ""));
+        assert!(logged_out.body.contains("Please log in"));
+
+        let right = route(
+            dir,
+            &request(
+                "POST",
+                "/2025/day/1/answer",
+                Some("abc"),
+                "level=2&answer=6",
+            ),
+        );
+        assert!(right.body.contains("That's the right answer!"));
+
+        let wrong = route(
+            dir,
+            &request(
+                "POST",
+                "/2025/day/1/answer",
+                Some("abc"),
+                "level=1&answer=4",
+            ),
+        );
+        assert!(wrong.body.contains("That's not the right answer."));
+
+        let encoded = route(
+            dir,
+            &request(
+                "POST",
+                "/2025/day/1/answer",
+                Some("abc"),
+                "level=1&answer=%33+",
+            ),
+        );
+        assert!(encoded.body.contains("That's the right answer!"));
+
+        let redirected = route(
+            dir,
+            &request("POST", "/2025/day/1/answer", None, "level=1&answer=3"),
+        );
+        assert_eq!(redirected.location.as_deref(), Some("/2025/auth/login"));
+    }
+
+    #[test]
+    fn test_form_decode() {
+        assert_eq!(form_decode("a+b%2Bc%2d").as_deref(), Some("a b+c-"));
+        assert_eq!(form_decode("%E2%9C%93").as_deref(), Some("\u{2713}"));
+        assert_eq!(form_decode("100%"), None);
+        assert_eq!(form_decode("%zz"), None);
+        assert_eq!(form_decode("%ff"), None);
+    }
+
+    #[test]
+    fn test_read_request_limits_body() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        write!(
+            client,
+            "POST /2025/day/1/answer HTTP/1.1\r\nContent-Length: 18446744073709551615\r\n\r\n"
+        )
+        .unwrap();
+
+        let (stream, _) = listener.accept().unwrap();
+        let err = read_request(&stream).err().unwrap();
+        assert!(err.to_string().contains("over the 65536 byte limit"));
+    }
+
+    #[test]
+    fn test_http_client_against_mock_server() {
+        let temp = fixture("client");
+        let server = MockServer::bind("127.0.0.1:0", temp.path()).unwrap();
+        let base_url = format!("http://{}", server.local_addr().unwrap());
+        thread::spawn(move || server.serve());
+
+        let client = HttpClient::new(&base_url, "abc").unwrap();
+        assert_eq!(client.input(2025, 1).unwrap(), "L68\nR48\n");
+        assert_eq!(client.user(2025).unwrap(), USER);
+        let description = client.description(2025, 1).unwrap();
+        assert!(description.contains("<h2>--- Day 1 ---</h2>"));
+        let reply = client.submit(2025, 1, 1, "3").unwrap();
+        assert!(reply.contains("That's the right answer!"));
+
+        let logged_out = HttpClient::new(&base_url, "").unwrap();
+        let err = logged_out.input(2025, 1).unwrap_err();
+        assert!(err.to_string().contains("aoc auth login"));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    alloc::AllocStats,
+    runner::{PartReport, Status},
+    timer::format_duration,
+};
+use std::time::Duration;
+
+/// Format defines how the results of `aoc run` are printed.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum Format {
+    /// Only the answers, one per line
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// A header row, then one row per part
+    Csv,
+}
+
+const COLUMNS: [&str; 14] = [
+    "year",
+    "day",
+    "part",
+    "status",
+    "answer",
+    "error",
+    "parse_ns",
+    "solve_ns",
+    "parse_peak_bytes",
+    "parse_total_bytes",
+    "parse_allocations",
+    "solve_peak_bytes",
+    "solve_total_bytes",
+    "solve_allocations",
+];
+
+/// Prints the reports to stdout. In text format anything other than an
+/// answer goes to stderr, including the timings and allocations asked for
+/// with `stats`.
+pub fn print(reports: &[PartReport], format: Format, stats: bool) -> Result<(), anyhow::Error> {
+    match format {
+        Format::Text => {
+            for report in reports {
+                match report.status {
+                    Status::Ok => {
+                        println!("{}", report.answer.as_deref().unwrap_or_default());
+                        if stats {
+                            eprintln!("{}", stats_line(report));
+                        }
+                    }
+                    Status::Unimplemented => {
+                        eprintln!("Day {} part {} is not implemented", report.day, report.part)
+                    }
+                    Status::Error => eprintln!(
+                        "Day {} part {} failed: {}",
+                        report.day,
+                        report.part,
+                        report.error.as_deref().unwrap_or_default()
+                    ),
+                }
+            }
+        }
+        Format::Json => {
+            let rows: Vec<serde_json::Value> = reports.iter().map(json_row).collect();
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+        Format::Csv => {
+            println!("{}", COLUMNS.join(","));
+            for report in reports {
+                println!("{}", csv_row(report));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Describes where the time and memory for a part went, e.g.
+/// `Day 4 part 2: parse 1.20ms (3 allocations, 20.00KiB total, 20.00KiB peak), solve ...`.
+fn stats_line(report: &PartReport) -> String {
+    let phase = |duration: Option<Duration>, alloc: Option<AllocStats>| {
+        let duration = duration.map(format_duration).unwrap_or_default();
+        match alloc {
+            Some(alloc) => format!("{} ({})", duration, alloc),
+            None => duration,
+        }
+    };
+    format!(
+        "Day {} part {}: parse {}, solve {}",
+        report.day,
+        report.part,
+        phase(report.parse_duration, report.parse_alloc),
+        phase(report.solve_duration, report.solve_alloc)
+    )
+}
+
+fn json_row(report: &PartReport) -> serde_json::Value {
+    serde_json::json!({
+        "year": report.year,
+        "day": report.day,
+        "part": report.part,
+        "status": report.status.as_str(),
+        "answer": report.answer,
+        "error": report.error,
+        "parse_ns": report.parse_duration.map(nanos),
+        "solve_ns": report.solve_duration.map(nanos),
+        "parse_peak_bytes": report.parse_alloc.map(|a| a.peak_bytes),
+        "parse_total_bytes": report.parse_alloc.map(|a| a.total_bytes),
+        "parse_allocations": report.parse_alloc.map(|a| a.count),
+        "solve_peak_bytes": report.solve_alloc.map(|a| a.peak_bytes),
+        "solve_total_bytes": report.solve_alloc.map(|a| a.total_bytes),
+        "solve_allocations": report.solve_alloc.map(|a| a.count),
+    })
+}
+
+fn csv_row(report: &PartReport) -> String {
+    let alloc_fields = |alloc: Option<AllocStats>| match alloc {
+        Some(a) => [a.peak_bytes, a.total_bytes, a.count].map(|n| n.to_string()),
+        None => Default::default(),
+    };
+
+    [
+        report.year.to_string(),
+        report.day.to_string(),
+        report.part.to_string(),
+        report.status.as_str().to_string(),
+        csv_field(report.answer.as_deref().unwrap_or_default()),
+        csv_field(report.error.as_deref().unwrap_or_default()),
+        report
+            .parse_duration
+            .map(nanos)
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+        report
+            .solve_duration
+            .map(nanos)
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+    ]
+    .into_iter()
+    .chain(alloc_fields(report.parse_alloc))
+    .chain(alloc_fields(report.solve_alloc))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// Quotes a field if it would otherwise break the row.
+pub(crate) fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn report(status: Status) -> PartReport {
+        PartReport {
+            year: 2025,
+            day: 3,
+            part: 2,
+            status,
+            answer: None,
+            error: None,
+            parse_duration: None,
+            solve_duration: None,
+            parse_alloc: None,
+            solve_alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let ok = PartReport {
+            answer: Some("357".to_string()),
+            parse_duration: Some(Duration::from_micros(2)),
+            solve_duration: Some(Duration::from_nanos(150)),
+            solve_alloc: Some(AllocStats {
+                peak_bytes: 64,
+                total_bytes: 96,
+                count: 2,
+            }),
+            ..report(Status::Ok)
+        };
+        assert_eq!(csv_row(&ok), "2025,3,2,ok,357,,2000,150,,,,64,96,2");
+
+        let failed = PartReport {
+            error: Some("line 1, column 2: bad \"x\"".to_string()),
+            ..report(Status::Error)
+        };
+        assert_eq!(
+            csv_row(&failed),
+            "2025,3,2,error,,\"line 1, column 2: bad \"\"x\"\"\",,,,,,,,"
+        );
+    }
+
+    #[test]
+    fn test_json_row() {
+        let row = json_row(&report(Status::Unimplemented));
+        assert_eq!(row["status"], "unimplemented");
+        assert_eq!(row["day"], 3);
+        assert!(row["answer"].is_null());
+        assert!(row["solve_ns"].is_null());
+        assert!(row["solve_allocations"].is_null());
+    }
+
+    #[test]
+    fn test_stats_line() {
+        let ok = PartReport {
+            parse_duration: Some(Duration::from_micros(2)),
+            solve_duration: Some(Duration::from_nanos(150)),
+            solve_alloc: Some(AllocStats {
+                peak_bytes: 2048,
+                total_bytes: 4096,
+                count: 3,
+            }),
+            ..report(Status::Ok)
+        };
+        assert_eq!(
+            stats_line(&ok),
+            "Day 3 part 2: parse 2.00µs, solve 150ns (3 allocations, 4.00KiB total, 2.00KiB peak)"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::anyhow;
+
+/// Parses lines made up of single decimal digits, one row per line.
+///
+/// Both `\n` and `\r\n` line endings are accepted, and blank lines at the
+/// end are ignored. A blank line before the end, or any other character, is
+/// rejected with its line and column (both counted from 1).
+pub fn digit_lines(input: &str) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+    let lines: Vec<&str> = input.lines().collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .map_or(0, |idx| idx + 1);
+
+    lines[..end]
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            if is_blank(line) {
+                return Err(anyhow!("line {}: unexpected blank line", idx + 1));
+            }
+            Ok(parse_digits(idx + 1, line)?)
+        })
+        .collect()
+}
+
+fn is_blank(line: &str) -> bool {
+    line.strip_suffix('\r').unwrap_or(line).is_empty()
+}
+
+/// Removes HTML tags and decodes the entities the site's pages use.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&gt;", ">")
+        .replace("&lt;", "<")
+        .replace("&amp;", "&")
+}
+
+/// The text of every `<article>` on a page, which is where the site puts
+/// puzzle descriptions and its replies to answers. Headings, paragraphs and
+/// articles are separated by blank lines. `None` if the page has none.
+pub fn articles(html: &str) -> Option<String> {
+    let texts: Vec<String> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|rest| {
+            let body = &rest[rest.find('>')? + 1..];
+            let end = body.find("</article>").unwrap_or(body.len());
+            let body = body[..end]
+                .replace("</h2>", "</h2>\n\n")
+                .replace("</p>", "</p>\n\n");
+            Some(strip_tags(&body).trim().to_string())
+        })
+        .collect();
+    (!texts.is_empty()).then(|| texts.join("\n\n"))
+}
+
+/// A character which is not a digit.
+#[derive(Debug)]
+struct InvalidDigit {
+    line: usize,
+    column: usize,
+    found: char,
+}
+
+impl std::fmt::Display for InvalidDigit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected a digit, found {:?}",
+            self.line, self.column, self.found
+        )
+    }
+}
+
+impl std::error::Error for InvalidDigit {}
+
+fn parse_digits(line_number: usize, line: &str) -> Result<Vec<u8>, InvalidDigit> {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+
+    line.chars()
+        .enumerate()
+        .map(|(idx, c)| match c {
+            '0'..='9' => Ok(c as u8 - b'0'),
+            _ => Err(InvalidDigit {
+                line: line_number,
+                column: idx + 1,
+                found: c,
+            }),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_digit_lines() {
+        let digits = digit_lines("123\r\n405\n").unwrap();
+        assert_eq!(digits, vec![vec![1, 2, 3], vec![4, 0, 5]]);
+
+        let digits = digit_lines("123\n405\n\r\n\n").unwrap();
+        assert_eq!(digits, vec![vec![1, 2, 3], vec![4, 0, 5]]);
+        assert!(digit_lines("\n\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_digit_lines_invalid() {
+        let err = digit_lines("123\n45 \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a digit, found ' '"
+        );
+
+        let err = digit_lines("12\n\n34\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: unexpected blank line");
+
+        let err = digit_lines("1\r2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 2: expected a digit, found '\\r'"
+        );
+    }
+
+    #[test]
+    fn test_articles() {
+        let page = concat!(
+            r#"<main><article class="day-desc"><h2>--- Day 1 ---</h2>"#,
+            "<p>Turn the &lt;dial&gt;.</p></article><p>Answer:</p>",
+            "<article><p>Part two</p></article></main>",
+        );
+        assert_eq!(
+            articles(page).as_deref(),
+            Some("--- Day 1 ---\n\nTurn the <dial>.\n\nPart two")
+        );
+        assert_eq!(articles("<main></main>"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_digit_lines_never_panics(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+            let _ = digit_lines(&String::from_utf8_lossy(&bytes));
+        }
+    }
+}
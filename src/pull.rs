@@ -0,0 +1,98 @@
+use crate::{
+    calendar::{self, Clock},
+    client::AocClient,
+    parse,
+};
+use anyhow::anyhow;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Downloads a day's input into `input_dir`, returning where it was saved.
+/// Puzzles which have not unlocked by `clock` are refused without asking
+/// the site.
+pub fn pull(
+    client: &impl AocClient,
+    clock: &impl Clock,
+    input_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<PathBuf, anyhow::Error> {
+    calendar::ensure_unlocked(clock, year, day)?;
+    let input = client.input(year, day)?;
+
+    let dir = input_dir.join(year.to_string());
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("day_{:02}.txt", day));
+    fs::write(&path, input)?;
+
+    Ok(path)
+}
+
+/// Fetches the text of a day's puzzle description, refusing puzzles which
+/// have not unlocked by `clock` without asking the site.
+pub fn fetch(
+    client: &impl AocClient,
+    clock: &impl Clock,
+    year: u16,
+    day: u8,
+) -> Result<String, anyhow::Error> {
+    calendar::ensure_unlocked(clock, year, day)?;
+    let page = client.description(year, day)?;
+    parse::articles(&page).ok_or_else(|| anyhow!("No description on the page for day {}", day))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        calendar::{FakeClock, unlock_time},
+        client::test::FakeClient,
+        test_support::TempDir,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_pull() {
+        let temp = TempDir::new("pull");
+        let dir = temp.path();
+        let client = FakeClient {
+            input: Some("L68\nR48\n"),
+            ..FakeClient::default()
+        };
+
+        let clock = FakeClock::at(unlock_time(2025, 2).unwrap());
+
+        let path = pull(&client, &clock, dir, 2025, 1).unwrap();
+        assert_eq!(path, dir.join("2025").join("day_01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "L68\nR48\n");
+
+        let refused = pull(&FakeClient::default(), &clock, dir, 2025, 2);
+        assert!(refused.is_err());
+        assert!(!dir.join("2025").join("day_02.txt").exists());
+
+        let early = FakeClock::at(unlock_time(2025, 3).unwrap() - Duration::from_secs(90));
+        let err = pull(&client, &early, dir, 2025, 3).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Day 3 of 2025 unlocks in 1m 30s")
+        );
+        assert!(!dir.join("2025").join("day_03.txt").exists());
+        assert_eq!(client.requests.get(), 1);
+    }
+
+    #[test]
+    fn test_fetch() {
+        let client = FakeClient {
+            description: Some("<main><article><h2>--- Day 1 ---</h2></article></main>"),
+            ..FakeClient::default()
+        };
+        let clock = FakeClock::at(unlock_time(2025, 1).unwrap());
+        assert_eq!(fetch(&client, &clock, 2025, 1).unwrap(), "--- Day 1 ---");
+
+        let err = fetch(&client, &clock, 2025, 2).unwrap_err();
+        assert!(err.to_string().starts_with("Day 2 of 2025 unlocks in"));
+        assert_eq!(client.requests.get(), 1);
+    }
+}
@@ -1,6 +1,109 @@
+use anyhow::anyhow;
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
 /// Parts defines which parts of a day's puzzle should be run.
+#[derive(Clone, Copy, Debug)]
 pub enum Parts {
     Both,
     First,
     Second,
 }
+
+impl Parts {
+    /// The part numbers to run, in order.
+    pub fn numbers(&self) -> &'static [u8] {
+        match self {
+            Parts::Both => &[1, 2],
+            Parts::First => &[1],
+            Parts::Second => &[2],
+        }
+    }
+}
+
+/// Solution is implemented by each day's puzzle so that the runner can read,
+/// parse and solve it.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts. Parts may run on
+    /// separate threads, so it must be safe to share.
+    type Input: Send + Sync + 'static;
+
+    const NAME: &'static str;
+    /// The example input, for when the real one is not around.
+    const EXAMPLE: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, anyhow::Error>;
+
+    fn part_1(input: &Self::Input) -> Result<String, anyhow::Error>;
+
+    fn part_2(input: &Self::Input) -> Result<String, anyhow::Error>;
+}
+
+/// Unimplemented is returned by a part which has not been solved yet, so
+/// that it is reported as such rather than as a wrong answer or a failure.
+#[derive(Debug)]
+pub struct Unimplemented;
+
+impl std::fmt::Display for Unimplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl std::error::Error for Unimplemented {}
+
+/// DayVisitor is shown every registered day of an event in turn, so that
+/// anything which needs all of them works from the same list.
+pub trait DayVisitor {
+    fn visit<S: Solution>(&mut self, year: u16, day: u8);
+}
+
+/// Reads and parses the input for a day.
+pub fn load<S: Solution>(year: u16, day: u8) -> Result<S::Input, anyhow::Error> {
+    S::parse(&read_input(year, day)?)
+}
+
+/// Sets the directory inputs are read from. Only the first call has any
+/// effect; until then inputs are read from `inputs`.
+pub fn set_input_dir(dir: PathBuf) {
+    let _ = INPUT_DIR.set(dir);
+}
+
+pub fn input_dir() -> &'static Path {
+    INPUT_DIR.get_or_init(|| PathBuf::from("inputs"))
+}
+
+/// Reads the raw input for a day.
+pub fn read_input(year: u16, day: u8) -> Result<String, anyhow::Error> {
+    let path = input_path(year, day);
+    read_to_string(&path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Where a day's input lives, following the layout `aoc pull` writes.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("day_{:02}.txt", day))
+}
+
+/// The known-good answers saved next to a day's input in `day_XX.answers`:
+/// part one on the first line and part two on the second. A missing file
+/// or blank line means the answer is not known.
+pub fn expected_answers(year: u16, day: u8) -> Result<[Option<String>; 2], anyhow::Error> {
+    let path = input_path(year, day).with_extension("answers");
+    let contents = match read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok([None, None]),
+        Err(e) => return Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+    };
+
+    let mut lines = contents
+        .lines()
+        .map(|line| Some(line.trim().to_string()).filter(|line| !line.is_empty()));
+    Ok([lines.next().flatten(), lines.next().flatten()])
+}
@@ -0,0 +1,88 @@
+const PROMPT: &str = "> ";
+
+/// Reads commands a line at a time and prints what `eval` makes of them.
+///
+/// `help` prints `help` and `quit` or `exit` ends the session, as does the
+/// end of input. Errors from `eval` are printed and the session carries on.
+pub fn run<F>(help: &str, mut eval: F) -> Result<(), anyhow::Error>
+where
+    F: FnMut(&str) -> Result<String, anyhow::Error>,
+{
+    let mut lines = Lines::new()?;
+
+    while let Some(line) = lines.next()? {
+        let line = line.trim();
+        match line {
+            "" => continue,
+            "quit" | "exit" => break,
+            "help" => println!("{}", help),
+            _ => match eval(line) {
+                Ok(output) => println!("{}", output),
+                Err(e) => eprintln!("{}", e),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Source of input lines, with line editing when the `line-editing` feature
+/// is enabled.
+#[cfg(feature = "line-editing")]
+struct Lines {
+    editor: rustyline::DefaultEditor,
+}
+
+#[cfg(feature = "line-editing")]
+impl Lines {
+    fn new() -> Result<Self, anyhow::Error> {
+        Ok(Lines {
+            editor: rustyline::DefaultEditor::new()?,
+        })
+    }
+
+    fn next(&mut self) -> Result<Option<String>, anyhow::Error> {
+        use rustyline::error::ReadlineError;
+
+        match self.editor.readline(PROMPT) {
+            Ok(line) => {
+                self.editor.add_history_entry(&line)?;
+                Ok(Some(line))
+            }
+            Err(ReadlineError::Eof | ReadlineError::Interrupted) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(not(feature = "line-editing"))]
+struct Lines {
+    interactive: bool,
+}
+
+#[cfg(not(feature = "line-editing"))]
+impl Lines {
+    fn new() -> Result<Self, anyhow::Error> {
+        use std::io::IsTerminal;
+
+        Ok(Lines {
+            interactive: std::io::stdin().is_terminal(),
+        })
+    }
+
+    fn next(&mut self) -> Result<Option<String>, anyhow::Error> {
+        use std::io::{BufRead, Write, stdin, stdout};
+
+        // Only prompt a person, so piped output stays clean.
+        if self.interactive {
+            print!("{}", PROMPT);
+            stdout().flush()?;
+        }
+
+        let mut line = String::new();
+        match stdin().lock().read_line(&mut line)? {
+            0 => Ok(None),
+            _ => Ok(Some(line)),
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    alloc::{self, AllocStats},
+    puzzle::{Parts, Solution, Unimplemented, read_input},
+    timer::format_duration,
+};
+use anyhow::anyhow;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Set once a part times out. Its worker thread is left running and keeps
+/// allocating, so allocation counts from then on are not reported.
+static ABANDONED: AtomicBool = AtomicBool::new(false);
+
+/// Status is the outcome of running one part of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unimplemented => "unimplemented",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// PartReport records the answer to one part of a day's puzzle and how long
+/// it took, and how much it allocated, to get there.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// Time spent reading and parsing the input, shared by the day's parts.
+    pub parse_duration: Option<Duration>,
+    pub solve_duration: Option<Duration>,
+    /// Allocations while reading and parsing, if they were counted. They are
+    /// not counted when parts run in parallel or after a part timed out, as
+    /// the counters are shared by every thread.
+    pub parse_alloc: Option<AllocStats>,
+    pub solve_alloc: Option<AllocStats>,
+}
+
+impl PartReport {
+    fn new(year: u16, day: u8, part: u8, status: Status) -> Self {
+        PartReport {
+            year,
+            day,
+            part,
+            status,
+            answer: None,
+            error: None,
+            parse_duration: None,
+            solve_duration: None,
+            parse_alloc: None,
+            solve_alloc: None,
+        }
+    }
+}
+
+/// Options controls how the runner solves each part.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options {
+    /// Run each part on its own thread, sharing the parsed input.
+    pub parallel: bool,
+    /// Give up on a part which has not finished after this long.
+    pub timeout: Option<Duration>,
+}
+
+/// Reads, parses and solves the requested parts of a day's puzzle.
+///
+/// Errors and panics are recorded in the reports rather than returned, so
+/// one bad part does not hide the answer to the other.
+pub fn run<S: Solution>(year: u16, day: u8, parts: Parts, options: Options) -> Vec<PartReport> {
+    run_on::<S>(year, day, || read_input(year, day), parts, options)
+}
+
+/// Runs a day with its raw input from `read`.
+fn run_on<S: Solution>(
+    year: u16,
+    day: u8,
+    read: impl FnOnce() -> Result<String, anyhow::Error>,
+    parts: Parts,
+    options: Options,
+) -> Vec<PartReport> {
+    log::debug!("{}", S::NAME);
+
+    let start = Instant::now();
+    let (input, parse_alloc) =
+        alloc::measure(|| catch_panic(|| read().and_then(|raw| S::parse(&raw))));
+    let parse_duration = start.elapsed();
+    log::debug!("Parse duration {}", format_duration(parse_duration));
+    let parse_alloc = parse_alloc.filter(|_| counted(options));
+
+    let input = match input {
+        Ok(input) => Arc::new(input),
+        Err(e) if e.is::<Unimplemented>() => return unimplemented(year, day, parts),
+        Err(e) => {
+            return parts
+                .numbers()
+                .iter()
+                .map(|&part| PartReport {
+                    error: Some(format!("{:#}", e)),
+                    ..PartReport::new(year, day, part, Status::Error)
+                })
+                .collect();
+        }
+    };
+
+    let solve_part = |&part: &u8| {
+        let start = Instant::now();
+        let (answer, solve_alloc) =
+            alloc::measure(|| solve::<S>(Arc::clone(&input), part, options.timeout));
+        let solve_duration = start.elapsed();
+        log::debug!("Part {} duration {}", part, format_duration(solve_duration));
+        let solve_alloc = solve_alloc.filter(|_| counted(options));
+
+        let report = PartReport {
+            parse_duration: Some(parse_duration),
+            solve_duration: Some(solve_duration),
+            parse_alloc,
+            solve_alloc,
+            ..PartReport::new(year, day, part, Status::Ok)
+        };
+        match answer {
+            Ok(answer) => PartReport {
+                answer: Some(answer),
+                ..report
+            },
+            Err(e) if e.is::<Unimplemented>() => PartReport {
+                status: Status::Unimplemented,
+                ..report
+            },
+            Err(e) => PartReport {
+                status: Status::Error,
+                error: Some(format!("{:#}", e)),
+                ..report
+            },
+        }
+    };
+
+    if options.parallel {
+        parallel_map(parts.numbers(), solve_part)
+    } else {
+        parts.numbers().iter().map(solve_part).collect()
+    }
+}
+
+/// Solves one part, turning a panic into an error. With a timeout the part
+/// runs on a worker thread which is abandoned if it does not finish in time.
+fn solve<S: Solution>(
+    input: Arc<S::Input>,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<String, anyhow::Error> {
+    let solve = move || {
+        catch_panic(|| match part {
+            1 => S::part_1(&input),
+            _ => S::part_2(&input),
+        })
+    };
+
+    let Some(timeout) = timeout else {
+        return solve();
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("{} part {}", S::NAME, part))
+        .spawn(move || {
+            // The receiver is gone if the part timed out, so there is no one
+            // left to tell.
+            let _ = sender.send(solve());
+        })?;
+
+    receiver.recv_timeout(timeout).map_err(|_| {
+        ABANDONED.store(true, Ordering::Relaxed);
+        anyhow!("Timed out after {}", format_duration(timeout))
+    })?
+}
+
+/// Whether allocations counted just now belong to the part alone.
+fn counted(options: Options) -> bool {
+    !options.parallel && !ABANDONED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, reporting a panic as an error carrying the panic message.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T, anyhow::Error>) -> Result<T, anyhow::Error> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(anyhow!("Panicked: {}", message))
+    })
+}
+
+/// Applies `f` to every item on a pool of scoped threads, one per available
+/// core, and returns the results in the same order as the items.
+pub fn parallel_map<I, T, F>(items: &[I], f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len());
+    let next = AtomicUsize::new(0);
+
+    let mut results: Vec<(usize, T)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(idx) {
+                            Some(item) => done.push((idx, f(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Worker thread panicked"))
+            .collect()
+    });
+
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Reports the requested parts of a day with no solution.
+pub fn unimplemented(year: u16, day: u8, parts: Parts) -> Vec<PartReport> {
+    parts
+        .numbers()
+        .iter()
+        .map(|&part| PartReport::new(year, day, part, Status::Unimplemented))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A puzzle whose parts misbehave: part one panics and part two hangs.
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+
+        const NAME: &'static str = "Broken";
+        const EXAMPLE: &'static str = "";
+
+        fn parse(_input: &str) -> Result<(), anyhow::Error> {
+            Ok(())
+        }
+
+        fn part_1(_input: &()) -> Result<String, anyhow::Error> {
+            panic!("part one is broken")
+        }
+
+        fn part_2(_input: &()) -> Result<String, anyhow::Error> {
+            thread::sleep(Duration::from_secs(10));
+            Ok("too late".to_string())
+        }
+    }
+
+    #[test]
+    fn test_run_isolates_panics_and_timeouts() {
+        let options = Options {
+            parallel: false,
+            timeout: Some(Duration::from_secs(1)),
+        };
+        let reports = run_on::<Broken>(2025, 1, || Ok(String::new()), Parts::Both, options);
+
+        assert_eq!(reports[0].status, Status::Error);
+        assert_eq!(
+            reports[0].error.as_deref(),
+            Some("Panicked: part one is broken")
+        );
+        assert_eq!(reports[1].status, Status::Error);
+        assert_eq!(reports[1].error.as_deref(), Some("Timed out after 1.00s"));
+        assert_eq!(reports[1].solve_alloc, None);
+    }
+
+    /// A puzzle with only its first part solved.
+    struct Half;
+
+    impl Solution for Half {
+        type Input = ();
+
+        const NAME: &'static str = "Half";
+        const EXAMPLE: &'static str = "";
+
+        fn parse(_input: &str) -> Result<(), anyhow::Error> {
+            Ok(())
+        }
+
+        fn part_1(_input: &()) -> Result<String, anyhow::Error> {
+            Ok("42".to_string())
+        }
+
+        fn part_2(_input: &()) -> Result<String, anyhow::Error> {
+            Err(Unimplemented.into())
+        }
+    }
+
+    #[test]
+    fn test_run_reports_unimplemented_parts() {
+        let reports = run_on::<Half>(
+            2025,
+            1,
+            || Ok(String::new()),
+            Parts::Both,
+            Options::default(),
+        );
+
+        assert_eq!(reports[0].status, Status::Ok);
+        assert_eq!(reports[0].answer.as_deref(), Some("42"));
+        assert_eq!(reports[1].status, Status::Unimplemented);
+        assert_eq!(reports[1].answer, None);
+        assert_eq!(reports[1].error, None);
+    }
+
+    #[test]
+    fn test_run_reports_unimplemented_parsing() {
+        let reports = run_on::<crate::y2025::day_08::Puzzle>(
+            2025,
+            8,
+            || Ok(String::new()),
+            Parts::Both,
+            Options::default(),
+        );
+        assert!(
+            reports
+                .iter()
+                .all(|report| report.status == Status::Unimplemented)
+        );
+    }
+
+    #[test]
+    fn test_run_parallel_does_not_count_allocations() {
+        let options = Options {
+            parallel: true,
+            timeout: None,
+        };
+        for report in run_on::<Half>(2025, 1, || Ok(String::new()), Parts::Both, options) {
+            assert_eq!(report.parse_alloc, None);
+            assert_eq!(report.solve_alloc, None);
+        }
+    }
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u64> = (0..100).collect();
+        let squares = parallel_map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(parallel_map(&[] as &[u64], |n| *n).is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! `aoc stats`: the user's times and ranks from the site next to how the
+//! local solutions do.
+
+use crate::{
+    parse,
+    runner::{PartReport, Status},
+    timer::format_duration,
+};
+use anyhow::anyhow;
+use std::{fmt::Write, time::Duration};
+
+/// SiteDay is one row of the personal stats page.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SiteDay {
+    pub day: u8,
+    /// Part one and part two; `None` where the star has not been earned.
+    pub parts: [Option<SitePart>; 2],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SitePart {
+    /// As the site shows it, e.g. `00:12:34` or `>24h`.
+    pub time: String,
+    /// Events without a global leaderboard do not rank.
+    pub rank: Option<u64>,
+}
+
+/// LocalDay is how a day's local solution compares to its saved answers.
+#[derive(Clone, Debug)]
+pub struct LocalDay {
+    pub day: u8,
+    /// Both parts, or nothing when there is no input to run on.
+    pub reports: Vec<PartReport>,
+    pub expected: [Option<String>; 2],
+}
+
+/// Parses the table in the `<pre>` of `/<year>/leaderboard/self`. Each row
+/// is a day number followed by the same columns for each part, with `-` in
+/// every column of a part not yet solved.
+pub fn parse_self(html: &str) -> Result<Vec<SiteDay>, anyhow::Error> {
+    if html.contains("/auth/login") && !html.contains(r#"<div class="user">"#) {
+        return Err(crate::client::login_required());
+    }
+    let start = html
+        .find("<pre>")
+        .ok_or_else(|| anyhow!("No stats table on the page; have you solved anything yet?"))?;
+    let end = html[start..]
+        .find("</pre>")
+        .map(|end| start + end)
+        .ok_or_else(|| anyhow!("The stats table is not closed"))?;
+    let table = parse::strip_tags(&html[start + "<pre>".len()..end]);
+
+    let mut days = Vec::new();
+    for line in table.lines() {
+        let mut columns = line.split_whitespace();
+        let Some(Ok(day)) = columns.next().map(str::parse::<u8>) else {
+            // The header rows start with dashes or "Day".
+            continue;
+        };
+        let columns: Vec<&str> = columns.collect();
+        if columns.is_empty() || !columns.len().is_multiple_of(2) {
+            return Err(anyhow!("Unexpected stats row for day {}: {:?}", day, line));
+        }
+        let (first, second) = columns.split_at(columns.len() / 2);
+        days.push(SiteDay {
+            day,
+            parts: [parse_part(first)?, parse_part(second)?],
+        });
+    }
+
+    days.sort_by_key(|day| day.day);
+    Ok(days)
+}
+
+fn parse_part(columns: &[&str]) -> Result<Option<SitePart>, anyhow::Error> {
+    match columns {
+        ["-", ..] => Ok(None),
+        [time] => Ok(Some(SitePart {
+            time: time.to_string(),
+            rank: None,
+        })),
+        [time, rank, ..] => Ok(Some(SitePart {
+            time: time.to_string(),
+            rank: Some(
+                rank.parse()
+                    .map_err(|_| anyhow!("Unexpected rank {:?}", rank))?,
+            ),
+        })),
+        [] => Ok(None),
+    }
+}
+
+/// Renders a row per day found on the site or locally. The local column
+/// has a mark per part: `*` reproduces the saved answer, `x` does not, `?`
+/// has no saved answer to check, `-` is not implemented and `!` failed.
+pub fn render(site: &[SiteDay], local: &[LocalDay]) -> String {
+    let mut days: Vec<u8> = site
+        .iter()
+        .map(|day| day.day)
+        .chain(local.iter().map(|day| day.day))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:>3}  {:<5}  {:<17}  {:<17}  {:<5}  Runtime",
+        "Day", "Stars", "Part 1", "Part 2", "Local"
+    )
+    .unwrap();
+
+    for day in days {
+        let site_day = site.iter().find(|site_day| site_day.day == day);
+        let site_parts = site_day
+            .map(|site_day| site_day.parts.clone())
+            .unwrap_or_default();
+        let stars: String = site_parts
+            .iter()
+            .map(|part| if part.is_some() { '*' } else { ' ' })
+            .collect();
+        let local_day = local
+            .iter()
+            .find(|local_day| local_day.day == day && !local_day.reports.is_empty());
+
+        writeln!(
+            out,
+            "{:>3}  {:<5}  {:<17}  {:<17}  {:<5}  {}",
+            day,
+            stars.trim_end(),
+            format_site_part(&site_parts[0]),
+            format_site_part(&site_parts[1]),
+            local_day
+                .map(local_marks)
+                .unwrap_or_else(|| "-".to_string()),
+            local_day
+                .and_then(runtime)
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string())
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+fn format_site_part(part: &Option<SitePart>) -> String {
+    match part {
+        Some(SitePart {
+            time,
+            rank: Some(rank),
+        }) => format!("{} (#{})", time, rank),
+        Some(SitePart { time, rank: None }) => time.clone(),
+        None => "-".to_string(),
+    }
+}
+
+fn local_marks(local: &LocalDay) -> String {
+    local
+        .reports
+        .iter()
+        .map(|report| {
+            let expected = local
+                .expected
+                .get(usize::from(report.part) - 1)
+                .cloned()
+                .flatten();
+            match (report.status, &report.answer, expected) {
+                (Status::Ok, Some(answer), Some(expected)) if *answer == expected => '*',
+                (Status::Ok, Some(_), Some(_)) => 'x',
+                (Status::Ok, _, None) => '?',
+                (Status::Unimplemented, _, _) => '-',
+                _ => '!',
+            }
+        })
+        .collect()
+}
+
+/// The parse time, counted once, plus the time for each part.
+fn runtime(local: &LocalDay) -> Option<Duration> {
+    let parse = local.reports.first()?.parse_duration?;
+    let solve: Duration = local
+        .reports
+        .iter()
+        .filter_map(|report| report.solve_duration)
+        .sum();
+    Some(parse + solve)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard_self.html");
+
+    fn report(part: u8, answer: &str) -> PartReport {
+        PartReport {
+            year: 2025,
+            day: 1,
+            part,
+            status: Status::Ok,
+            answer: Some(answer.to_string()),
+            error: None,
+            parse_duration: Some(Duration::from_micros(30)),
+            solve_duration: Some(Duration::from_micros(10)),
+            parse_alloc: None,
+            solve_alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_self() {
+        let days = parse_self(FIXTURE).unwrap();
+
+        assert_eq!(days.len(), 3);
+        assert_eq!(
+            days[0],
+            SiteDay {
+                day: 1,
+                parts: [
+                    Some(SitePart {
+                        time: "00:12:34".to_string(),
+                        rank: Some(1201)
+                    }),
+                    Some(SitePart {
+                        time: "00:20:01".to_string(),
+                        rank: Some(1340)
+                    }),
+                ],
+            }
+        );
+        assert_eq!(days[1].parts[0].as_ref().unwrap().time, ">24h");
+        assert_eq!(days[1].parts[1], None);
+    }
+
+    #[test]
+    fn test_parse_self_without_ranks() {
+        let html = "<pre>Day   Part 1   Part 2\n  4   00:05:00        -\n</pre>";
+        let days = parse_self(html).unwrap();
+        assert_eq!(days[0].parts[0].as_ref().unwrap().rank, None);
+        assert_eq!(days[0].parts[1], None);
+    }
+
+    #[test]
+    fn test_parse_self_logged_out() {
+        let html = r#"<a href="/2025/auth/login">[Log In]</a>"#;
+        assert!(
+            parse_self(html)
+                .unwrap_err()
+                .to_string()
+                .contains("aoc auth login")
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let site = parse_self(FIXTURE).unwrap();
+        let local = [
+            LocalDay {
+                day: 1,
+                reports: vec![report(1, "3"), report(2, "7")],
+                expected: [Some("3".to_string()), Some("6".to_string())],
+            },
+            LocalDay {
+                day: 4,
+                reports: vec![report(1, "13"), report(2, "43")],
+                expected: [None, None],
+            },
+        ];
+
+        assert_eq!(
+            render(&site, &local),
+            concat!(
+                "Day  Stars  Part 1             Part 2             Local  Runtime\n",
+                "  1  **     00:12:34 (#1201)   00:20:01 (#1340)   *x     50.00µs\n",
+                "  2  *      >24h (#51002)      -                  -      -\n",
+                "  3  **     00:09:41 (#2214)   00:31:02 (#4410)   -      -\n",
+                "  4         -                  -                  ??     50.00µs\n",
+            )
+        );
+    }
+}
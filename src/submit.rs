@@ -0,0 +1,52 @@
+//! `aoc submit`: sends an answer to the site and reports its verdict.
+
+use crate::{
+    calendar::{self, Clock},
+    client::AocClient,
+    parse,
+};
+use anyhow::anyhow;
+
+/// Submits an answer to one part of a day, returning the site's reply.
+/// Puzzles which have not unlocked by `clock` are refused without asking
+/// the site.
+pub fn submit(
+    client: &impl AocClient,
+    clock: &impl Clock,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<String, anyhow::Error> {
+    calendar::ensure_unlocked(clock, year, day)?;
+    let page = client.submit(year, day, part, answer)?;
+    parse::articles(&page).ok_or_else(|| anyhow!("The site's reply had no message"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        calendar::{FakeClock, unlock_time},
+        client::test::FakeClient,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_submit() {
+        let client = FakeClient {
+            reply: Some("<main><article><p>That's the right answer!</p></article></main>"),
+            ..FakeClient::default()
+        };
+        let clock = FakeClock::at(unlock_time(2025, 1).unwrap());
+        assert_eq!(
+            submit(&client, &clock, 2025, 1, 1, "3").unwrap(),
+            "That's the right answer!"
+        );
+
+        let early = FakeClock::at(unlock_time(2025, 2).unwrap() - Duration::from_secs(5));
+        let err = submit(&client, &early, 2025, 2, 1, "3").unwrap_err();
+        assert!(err.to_string().starts_with("Day 2 of 2025 unlocks in 5s"));
+        assert_eq!(client.requests.get(), 1);
+    }
+}
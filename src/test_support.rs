@@ -0,0 +1,30 @@
+//! Helpers shared by the unit tests.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// TempDir is an empty directory for a test's files, removed when it is
+/// dropped, so a failing assertion does not leave it behind.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -9,6 +9,78 @@ pub fn format_duration(duration: Duration) -> String {
     } else if nanos < 1_000_000_000 {
         format!("{:.2}ms", nanos as f64 / 1_000_000.0)
     } else {
-        format!("{:.2}s", duration.as_secs())
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Parses a duration such as `500ms`, `2s` or `1m`. A bare number is seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration {:?}", s))?;
+
+    let seconds = match unit {
+        "ns" => value / 1_000_000_000.0,
+        "us" | "µs" => value / 1_000_000.0,
+        "ms" => value / 1_000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown unit {:?} in duration {:?}", unit, s)),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Formats the time left before something happens, e.g. `1h 02m 05s`.
+/// Partial seconds round up, so it never reads `0s` early.
+pub fn format_countdown(duration: Duration) -> String {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{}d {:02}h {:02}m {:02}s", days, hours, minutes, seconds)
+    } else if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+        assert!(parse_duration("fast").is_err());
+        assert!(parse_duration("3h").is_err());
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_millis(1_500)), "2s");
+        assert_eq!(format_countdown(Duration::from_secs(303)), "5m 03s");
+        assert_eq!(format_countdown(Duration::from_secs(3_725)), "1h 02m 05s");
+        assert_eq!(
+            format_countdown(Duration::from_secs(3 * 86_400 + 4 * 3_600 + 9)),
+            "3d 04h 00m 09s"
+        );
     }
 }
@@ -0,0 +1,123 @@
+use crate::output::csv_field;
+use std::{fmt, io::Write};
+
+/// TraceFormat defines how each step of a trace is printed.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum TraceFormat {
+    Text,
+    Csv,
+    Jsonl,
+}
+
+/// A single value in a trace row.
+pub enum Value {
+    Str(String),
+    Int(i64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Int(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// Tracer writes rows of named values, one line per step.
+pub struct Tracer<'a, W: Write> {
+    out: W,
+    format: TraceFormat,
+    columns: &'a [&'a str],
+}
+
+impl<'a, W: Write> Tracer<'a, W> {
+    /// Creates a tracer, writing the header line if the format has one.
+    pub fn new(mut out: W, format: TraceFormat, columns: &'a [&'a str]) -> std::io::Result<Self> {
+        if let TraceFormat::Csv = format {
+            writeln!(out, "{}", columns.join(","))?;
+        }
+
+        Ok(Tracer {
+            out,
+            format,
+            columns,
+        })
+    }
+
+    /// Writes one row. Values must be in the same order as the columns.
+    pub fn row(&mut self, values: &[Value]) -> std::io::Result<()> {
+        let line = match self.format {
+            TraceFormat::Text => self
+                .columns
+                .iter()
+                .zip(values)
+                .map(|(column, value)| format!("{}={}", column, value))
+                .collect::<Vec<_>>()
+                .join(" "),
+            TraceFormat::Csv => values
+                .iter()
+                .map(|value| csv_field(&value.to_string()))
+                .collect::<Vec<_>>()
+                .join(","),
+            TraceFormat::Jsonl => {
+                let fields: serde_json::Map<String, serde_json::Value> = self
+                    .columns
+                    .iter()
+                    .zip(values)
+                    .map(|(column, value)| {
+                        let value = match value {
+                            Value::Str(s) => serde_json::Value::from(s.as_str()),
+                            Value::Int(n) => serde_json::Value::from(*n),
+                        };
+                        (column.to_string(), value)
+                    })
+                    .collect();
+                serde_json::Value::Object(fields).to_string()
+            }
+        };
+
+        writeln!(self.out, "{}", line)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn trace(format: TraceFormat) -> String {
+        let mut out = Vec::new();
+        let mut tracer = Tracer::new(&mut out, format, &["turn", "position"]).unwrap();
+        tracer
+            .row(&[Value::Str("L68".to_string()), Value::Int(82)])
+            .unwrap();
+        tracer
+            .row(&[Value::Str("say \"hi\", ok".to_string()), Value::Int(-1)])
+            .unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            trace(TraceFormat::Text),
+            "turn=L68 position=82\nturn=say \"hi\", ok position=-1\n"
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            trace(TraceFormat::Csv),
+            "turn,position\nL68,82\n\"say \"\"hi\"\", ok\",-1\n"
+        );
+    }
+
+    #[test]
+    fn test_jsonl() {
+        assert_eq!(
+            trace(TraceFormat::Jsonl),
+            "{\"turn\":\"L68\",\"position\":82}\n{\"turn\":\"say \\\"hi\\\", ok\",\"position\":-1}\n"
+        );
+    }
+}
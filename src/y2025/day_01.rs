@@ -0,0 +1,302 @@
+use crate::{
+    puzzle::Solution,
+    trace::{TraceFormat, Tracer, Value},
+};
+use anyhow::anyhow;
+use std::io::stdout;
+
+const NAME: &str = "Day 1";
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+const DIAL_SIZE: u32 = 100;
+const DIAL_START: u32 = 50;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<i32>;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Vec<i32>, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(turns: &Vec<i32>) -> Result<String, anyhow::Error> {
+        Ok(part_1(turns).to_string())
+    }
+
+    fn part_2(turns: &Vec<i32>) -> Result<String, anyhow::Error> {
+        Ok(part_2(turns).to_string())
+    }
+}
+
+/// Prints the dial after every turn along with the zeros counted so far.
+pub fn trace(turns: &[i32], format: TraceFormat) -> Result<(), anyhow::Error> {
+    log::debug!("{} trace", NAME);
+
+    let mut tracer = Tracer::new(
+        stdout().lock(),
+        format,
+        &["turn", "position", "part_1", "part_2"],
+    )?;
+    for step in trace_turns(turns) {
+        tracer.row(&[
+            Value::Str(format_turn(step.turn)),
+            Value::Int(step.position),
+            Value::Int(step.part_1 as i64),
+            Value::Int(step.part_2 as i64),
+        ])?;
+    }
+
+    Ok(())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, anyhow::Error> {
+    input.lines().map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> Result<i32, anyhow::Error> {
+    let (dir, count) = line.split_at(1);
+
+    let sign = if dir == "L" {
+        -1
+    } else if dir == "R" {
+        1
+    } else {
+        return Err(anyhow!("Invalid direction"));
+    };
+
+    Ok(sign * count.parse::<i32>()?)
+}
+
+pub fn part_1(turns: &[i32]) -> u64 {
+    let mut dial = Dial::new(DIAL_SIZE, DIAL_START).expect("Puzzle dial is valid");
+
+    turns
+        .iter()
+        .filter(|&&turn| {
+            dial.turn(turn);
+            dial.position() == 0
+        })
+        .count() as u64
+}
+
+pub fn part_2(turns: &[i32]) -> u64 {
+    let mut dial = Dial::new(DIAL_SIZE, DIAL_START).expect("Puzzle dial is valid");
+
+    turns.iter().map(|&turn| dial.turn(turn)).sum()
+}
+
+/// The state of the dial after a single turn.
+#[derive(Debug, PartialEq)]
+struct Step {
+    turn: i32,
+    position: i64,
+    part_1: u64,
+    part_2: u64,
+}
+
+fn trace_turns(turns: &[i32]) -> Vec<Step> {
+    let mut dial = Dial::new(DIAL_SIZE, DIAL_START).expect("Puzzle dial is valid");
+    let mut part_1 = 0;
+    let mut part_2 = 0;
+
+    turns
+        .iter()
+        .map(|&turn| {
+            part_2 += dial.turn(turn);
+            if dial.position() == 0 {
+                part_1 += 1;
+            }
+            Step {
+                turn,
+                position: dial.position(),
+                part_1,
+                part_2,
+            }
+        })
+        .collect()
+}
+
+fn format_turn(turn: i32) -> String {
+    if turn < 0 {
+        format!("L{}", -turn)
+    } else {
+        format!("R{}", turn)
+    }
+}
+
+/// A circular safe dial with positions `0..size`.
+#[derive(Debug, Clone, Copy)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+}
+
+impl Dial {
+    /// Creates a dial with `size` positions pointing at `start`. The puzzle's
+    /// dial has 100 positions and starts at 50.
+    pub fn new(size: u32, start: u32) -> Result<Self, anyhow::Error> {
+        if size == 0 {
+            return Err(anyhow!("Dial must have at least one position"));
+        }
+        if start >= size {
+            return Err(anyhow!(
+                "Start position {} is off a dial of size {}",
+                start,
+                size
+            ));
+        }
+
+        Ok(Dial {
+            size: size as i64,
+            position: start as i64,
+        })
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// Turns the dial by `turn` clicks (negative is left) and returns the
+    /// number of clicks which left the dial pointing at zero, including the
+    /// one it lands on.
+    pub fn turn(&mut self, turn: i32) -> u64 {
+        let clicks = (turn as i64).abs();
+
+        // Clicks needed before the dial first points at zero. Starting on
+        // zero does not count, so that takes a full revolution.
+        let to_zero = match (self.position, turn.signum()) {
+            (0, _) => self.size,
+            (p, 1) => self.size - p,
+            (p, _) => p,
+        };
+
+        let zeros = if clicks < to_zero {
+            0
+        } else {
+            (clicks - to_zero) / self.size + 1
+        };
+
+        self.position = (self.position + turn as i64).rem_euclid(self.size);
+
+        zeros as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_1() {
+        let turns = parse_input(TEST_INPUT).unwrap();
+        let result = part_1(&turns);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let turns = parse_input(TEST_INPUT).unwrap();
+        let result = part_2(&turns);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_trace_turns() {
+        let turns = parse_input(TEST_INPUT).unwrap();
+        let steps = trace_turns(&turns);
+
+        assert_eq!(
+            steps[0],
+            Step {
+                turn: -68,
+                position: 82,
+                part_1: 0,
+                part_2: 1,
+            }
+        );
+
+        let last = steps.last().unwrap();
+        assert_eq!((last.part_1, last.part_2), (3, 6));
+        assert_eq!(
+            steps
+                .iter()
+                .map(|s| format_turn(s.turn))
+                .collect::<Vec<_>>(),
+            TEST_INPUT.lines().collect::<Vec<_>>()
+        );
+    }
+
+    /// Turns the dial one click at a time, counting every stop on zero.
+    fn simulate(size: i64, start: i64, turn: i32) -> (i64, u64) {
+        let step = turn.signum() as i64;
+        let mut position = start;
+        let mut zeros = 0;
+        for _ in 0..turn.abs() {
+            position = (position + step).rem_euclid(size);
+            if position == 0 {
+                zeros += 1;
+            }
+        }
+        (position, zeros)
+    }
+
+    #[test]
+    fn test_dial_edge_cases() {
+        // Landing exactly on zero from the left.
+        let mut dial = Dial::new(100, 5).unwrap();
+        assert_eq!(dial.turn(-5), 1);
+        assert_eq!(dial.position(), 0);
+
+        // Leaving zero does not count it again.
+        assert_eq!(dial.turn(-1), 0);
+        assert_eq!(dial.position(), 99);
+
+        // Starting on zero and making full revolutions.
+        let mut dial = Dial::new(100, 0).unwrap();
+        assert_eq!(dial.turn(200), 2);
+        assert_eq!(dial.turn(-200), 2);
+        assert_eq!(dial.turn(0), 0);
+        assert_eq!(dial.position(), 0);
+    }
+
+    #[test]
+    fn test_dial_invalid() {
+        assert!(Dial::new(0, 0).is_err());
+        assert!(Dial::new(100, 100).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_dial_matches_simulation(
+            size in 1_u32..200,
+            start in 0_u32..200,
+            turns in prop::collection::vec(-1000_i32..1000, 0..20),
+        ) {
+            let start = start % size;
+            let mut dial = Dial::new(size, start).unwrap();
+            let mut position = start as i64;
+
+            for turn in turns {
+                let (expected_position, expected_zeros) = simulate(size as i64, position, turn);
+                prop_assert_eq!(dial.turn(turn), expected_zeros);
+                prop_assert_eq!(dial.position(), expected_position);
+                position = expected_position;
+            }
+        }
+    }
+}
@@ -1,44 +1,51 @@
-use crate::{puzzle::Parts, timer::format_duration};
+use crate::puzzle::Solution;
 use anyhow::anyhow;
-use std::{fs::read_to_string, time::Instant};
 
 const NAME: &str = "Day 2";
-const INPUT_FILE: &str = "inputs/day_02.txt";
-
-pub fn run(parts: Parts) {
-    log::debug!("{}", NAME);
-
-    let range = parse_input(
-        read_to_string(INPUT_FILE)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {}", INPUT_FILE, e))
-            .trim(),
-    )
-    .expect("Failed to parse");
-
-    let start = Instant::now();
-    match parts {
-        Parts::Both => {
-            println!("{}", part_1(&range));
-            println!("{}", part_2(&range));
-        }
-        Parts::First => {
-            println!("{}", part_1(&range));
-        }
-        Parts::Second => {
-            println!("{}", part_2(&range));
-        }
-    };
-    let duration = start.elapsed();
-    log::debug!("Duration {}", format_duration(duration));
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Range>;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Vec<Range>, anyhow::Error> {
+        parse_input(input.trim())
+    }
+
+    fn part_1(ranges: &Vec<Range>) -> Result<String, anyhow::Error> {
+        Ok(part_1(ranges).to_string())
+    }
+
+    fn part_2(ranges: &Vec<Range>) -> Result<String, anyhow::Error> {
+        Ok(part_2(ranges).to_string())
+    }
 }
 
 #[derive(Debug)]
-struct Range {
+pub struct Range {
     from: u64,
     to: u64,
 }
 
-fn parse_input(input: &str) -> Result<Vec<Range>, anyhow::Error> {
+impl Range {
+    /// The first ID in the range.
+    pub fn from(&self) -> u64 {
+        self.from
+    }
+
+    /// The last ID in the range.
+    pub fn to(&self) -> u64 {
+        self.to
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Range>, anyhow::Error> {
     input.split(',').map(parse_line).collect()
 }
 
@@ -53,7 +60,7 @@ fn parse_line(line: &str) -> Result<Range, anyhow::Error> {
     })
 }
 
-fn part_1(ranges: &[Range]) -> u64 {
+pub fn part_1(ranges: &[Range]) -> u64 {
     ranges
         .iter()
         .flat_map(|range| range.from..=range.to)
@@ -61,7 +68,7 @@ fn part_1(ranges: &[Range]) -> u64 {
         .sum()
 }
 
-fn part_2(ranges: &[Range]) -> u64 {
+pub fn part_2(ranges: &[Range]) -> u64 {
     ranges
         .iter()
         .flat_map(|range| range.from..=range.to)
@@ -113,8 +120,6 @@ fn is_invalid_part_2(id: u64) -> bool {
 mod test {
     use super::*;
 
-    const TEST_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
     #[test]
     fn test_part_1() {
         let ranges = parse_input(TEST_INPUT).unwrap();
@@ -0,0 +1,251 @@
+use crate::{parse, puzzle::Solution};
+use anyhow::anyhow;
+use std::{
+    io::{IsTerminal, Write, stdout},
+    str::FromStr,
+};
+
+const NAME: &str = "Day 3";
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+const PART_1_BATTERIES: usize = 2;
+const PART_2_BATTERIES: usize = 12;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vec<u8>>;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(banks: &Vec<Vec<u8>>) -> Result<String, anyhow::Error> {
+        Ok(part_1(banks)?.to_string())
+    }
+
+    fn part_2(banks: &Vec<Vec<u8>>) -> Result<String, anyhow::Error> {
+        Ok(part_2(banks)?.to_string())
+    }
+}
+
+/// Prints every bank with the batteries chosen for each part highlighted.
+pub fn explain(banks: &[Vec<u8>]) -> Result<(), anyhow::Error> {
+    log::debug!("{} explain", NAME);
+
+    let mut out = stdout().lock();
+    let style = if out.is_terminal() {
+        Highlight::Bold
+    } else {
+        Highlight::Brackets
+    };
+
+    for (part, batteries) in [(1, PART_1_BATTERIES), (2, PART_2_BATTERIES)] {
+        writeln!(out, "Part {} ({} batteries)", part, batteries)?;
+        for bank in banks {
+            let joltage = calculate_max_joltage(bank, batteries)?;
+            writeln!(
+                out,
+                "{} {}",
+                highlight(bank, &joltage.indices, style),
+                joltage.digits
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+    parse::digit_lines(input)
+}
+
+pub fn part_1(banks: &[Vec<u8>]) -> Result<u64, anyhow::Error> {
+    banks
+        .iter()
+        .map(|bank| calculate_max_joltage(bank, PART_1_BATTERIES)?.value::<u64>())
+        .sum()
+}
+
+pub fn part_2(banks: &[Vec<u8>]) -> Result<u64, anyhow::Error> {
+    banks
+        .iter()
+        .map(|bank| calculate_max_joltage(bank, PART_2_BATTERIES)?.value::<u64>())
+        .sum()
+}
+
+/// The batteries turned on in a bank and the joltage they produce.
+#[derive(Debug, PartialEq)]
+struct Joltage {
+    /// Positions of the chosen batteries in the bank, in ascending order.
+    indices: Vec<usize>,
+    digits: String,
+}
+
+impl Joltage {
+    /// Converts the digits to a number. Fails rather than overflowing if the
+    /// joltage does not fit in `T`.
+    fn value<T>(&self) -> Result<T, anyhow::Error>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        Ok(self.digits.parse()?)
+    }
+}
+
+/// Finds the largest joltage made by turning on `batteries` batteries.
+///
+/// Keeps a stack of chosen batteries. A battery knocks smaller ones off the
+/// top of the stack while there are still enough batteries after it to make
+/// up the count, so the whole bank is read once.
+fn calculate_max_joltage(bank: &[u8], batteries: usize) -> Result<Joltage, anyhow::Error> {
+    if batteries > bank.len() {
+        return Err(anyhow!(
+            "Cannot turn on {} batteries in a bank of {}",
+            batteries,
+            bank.len()
+        ));
+    }
+
+    let mut skips = bank.len() - batteries;
+    let mut indices: Vec<usize> = Vec::with_capacity(bank.len());
+    for (idx, digit) in bank.iter().enumerate() {
+        while skips > 0 && indices.last().is_some_and(|&top| bank[top] < *digit) {
+            indices.pop();
+            skips -= 1;
+        }
+        indices.push(idx);
+    }
+    indices.truncate(batteries);
+
+    let digits = indices
+        .iter()
+        .map(|&idx| char::from_digit(bank[idx] as u32, 10).ok_or_else(|| anyhow!("Invalid digit")))
+        .collect::<Result<String, anyhow::Error>>()?;
+
+    Ok(Joltage { indices, digits })
+}
+
+/// How chosen batteries stand out when a bank is printed.
+#[derive(Copy, Clone, Debug)]
+enum Highlight {
+    Bold,
+    Brackets,
+}
+
+fn highlight(bank: &[u8], indices: &[usize], style: Highlight) -> String {
+    let mut chosen = indices.iter().peekable();
+
+    bank.iter()
+        .enumerate()
+        .map(|(idx, digit)| {
+            if chosen.next_if_eq(&&idx).is_none() {
+                return digit.to_string();
+            }
+            match style {
+                Highlight::Bold => format!("\x1b[1m{}\x1b[0m", digit),
+                Highlight::Brackets => format!("[{}]", digit),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bank(line: &str) -> Vec<u8> {
+        parse_input(line).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_part_1() {
+        let banks = parse_input(TEST_INPUT).unwrap();
+        let result = part_1(&banks).unwrap();
+        assert_eq!(result, 357);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let banks = parse_input(TEST_INPUT).unwrap();
+        let result = part_2(&banks).unwrap();
+        assert_eq!(result, 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_reports_raw_positions() {
+        let err = Puzzle::parse("\n123\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unexpected blank line");
+
+        let err = Puzzle::parse("12\n 34\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a digit, found ' '"
+        );
+
+        let err = Puzzle::parse("123\n456 \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a digit, found ' '"
+        );
+
+        assert_eq!(Puzzle::parse("12\r\n34\r\n").unwrap().len(), 2);
+
+        let banks = Puzzle::parse("987654321111111\n811111111111119\n\n").unwrap();
+        assert_eq!(part_1(&banks).unwrap(), 98 + 89);
+    }
+
+    #[test]
+    fn test_calculate_max_joltage() {
+        let banks = parse_input(TEST_INPUT).unwrap();
+        let max_js: Vec<u64> = vec![987654321111, 811111111119, 434234234278, 888911112111];
+
+        for (bank, max_joltage) in banks.iter().zip(max_js) {
+            let joltage = calculate_max_joltage(bank, max_joltage.to_string().len()).unwrap();
+            assert_eq!(max_joltage, joltage.value::<u64>().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_calculate_max_joltage_indices() {
+        let bank = bank("818181911112111");
+        let joltage = calculate_max_joltage(&bank, 2).unwrap();
+        assert_eq!(joltage.indices, vec![6, 11]);
+        assert_eq!(joltage.digits, "92");
+    }
+
+    #[test]
+    fn test_highlight() {
+        let bank = bank("818181911112111");
+        let joltage = calculate_max_joltage(&bank, 2).unwrap();
+        assert_eq!(
+            highlight(&bank, &joltage.indices, Highlight::Brackets),
+            "818181[9]1111[2]111"
+        );
+    }
+
+    #[test]
+    fn test_calculate_max_joltage_wide() {
+        let bank = bank("9876543210987654321098765");
+        let joltage = calculate_max_joltage(&bank, 24).unwrap();
+        assert_eq!(joltage.digits, "987654321987654321098765");
+        assert!(joltage.value::<u64>().is_err());
+        assert_eq!(joltage.value::<u128>().unwrap(), 987654321987654321098765);
+    }
+
+    #[test]
+    fn test_calculate_max_joltage_too_many_batteries() {
+        let bank = bank("1234");
+        assert!(calculate_max_joltage(&bank, 5).is_err());
+        assert_eq!(calculate_max_joltage(&bank, 4).unwrap().digits, "1234");
+        assert_eq!(calculate_max_joltage(&bank, 0).unwrap().digits, "");
+    }
+}
@@ -1,39 +1,43 @@
-use crate::{puzzle::Parts, timer::format_duration};
-use std::{fs::read_to_string, time::Instant};
+use crate::puzzle::Solution;
 
 const NAME: &str = "Day 4";
-const INPUT_FILE: &str = "inputs/day_04.txt";
-
-pub fn run(parts: Parts) {
-    log::debug!("{}", NAME);
-
-    let grid = parse_input(
-        read_to_string(INPUT_FILE)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {}", INPUT_FILE, e))
-            .trim(),
-    )
-    .expect("Failed to parse");
-
-    let start = Instant::now();
-    match parts {
-        Parts::Both => {
-            println!("{}", part_1(&grid));
-            println!("{}", part_2(&grid));
-        }
-        Parts::First => {
-            println!("{}", part_1(&grid));
-        }
-        Parts::Second => {
-            println!("{}", part_2(&grid));
-        }
-    };
-    let duration = start.elapsed();
-    log::debug!("Duration {}", format_duration(duration));
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Grid;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Grid, anyhow::Error> {
+        parse_input(input.trim())
+    }
+
+    fn part_1(grid: &Grid) -> Result<String, anyhow::Error> {
+        Ok(part_1(grid).to_string())
+    }
+
+    fn part_2(grid: &Grid) -> Result<String, anyhow::Error> {
+        Ok(part_2(grid).to_string())
+    }
 }
 
 // Grid represents the floor of the factory as viewed from overhead.
 #[derive(Clone)]
-struct Grid(Vec<Vec<bool>>);
+pub struct Grid(Vec<Vec<bool>>);
 
 impl Grid {
     fn neighbors(&self, x: usize, y: usize) -> Vec<bool> {
@@ -77,7 +81,7 @@ impl FromIterator<Vec<bool>> for Grid {
     }
 }
 
-fn parse_input(input: &str) -> Result<Grid, anyhow::Error> {
+pub fn parse_input(input: &str) -> Result<Grid, anyhow::Error> {
     input.lines().map(parse_line).collect()
 }
 
@@ -85,7 +89,7 @@ fn parse_line(line: &str) -> Result<Vec<bool>, anyhow::Error> {
     Ok(line.chars().map(|c| c == '@').collect())
 }
 
-fn part_1(grid: &Grid) -> u64 {
+pub fn part_1(grid: &Grid) -> u64 {
     let mut count = 0;
     for (y, row) in grid.0.iter().enumerate() {
         for (x, is_roll) in row.iter().enumerate() {
@@ -98,7 +102,7 @@ fn part_1(grid: &Grid) -> u64 {
     count
 }
 
-fn part_2(grid: &Grid) -> u64 {
+pub fn part_2(grid: &Grid) -> u64 {
     let mut grid = grid.clone();
 
     let mut count = 0;
@@ -130,17 +134,6 @@ fn part_2(grid: &Grid) -> u64 {
 mod test {
     use super::*;
 
-    const TEST_INPUT: &str = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
     #[test]
     fn test_part_1() {
         let rolls = parse_input(TEST_INPUT).unwrap();
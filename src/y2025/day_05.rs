@@ -0,0 +1,406 @@
+use crate::puzzle::Solution;
+use anyhow::anyhow;
+use std::{cmp::max, fmt};
+
+const NAME: &str = "Day 5";
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = DB;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<DB, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(db: &DB) -> Result<String, anyhow::Error> {
+        Ok(part_1(db).to_string())
+    }
+
+    fn part_2(db: &DB) -> Result<String, anyhow::Error> {
+        Ok(part_2(db).to_string())
+    }
+}
+
+// Ranges of fresh ingredients. Ranges are inclusive.
+#[derive(Debug, Clone)]
+pub struct Range {
+    from: u64,
+    to: u64,
+}
+
+impl Range {
+    /// The first ID in the range.
+    pub fn from(&self) -> u64 {
+        self.from
+    }
+
+    /// The last ID in the range.
+    pub fn to(&self) -> u64 {
+        self.to
+    }
+
+    pub fn contains(&self, n: &u64) -> bool {
+        &self.from <= n && n <= &self.to
+    }
+
+    /// Counts the IDs in the range. A range can cover every `u64`, one more
+    /// than fits in a `u64`.
+    fn len(&self) -> u128 {
+        u128::from(self.to - self.from) + 1
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.from, self.to)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DB {
+    /// Fresh ranges, sorted and merged so that none overlap or touch.
+    pub ranges: Vec<Range>,
+    /// Fresh ranges as they appear in the input.
+    pub parsed_ranges: Vec<Range>,
+    pub ids: Vec<u64>,
+}
+
+impl DB {
+    fn new(parsed_ranges: Vec<Range>, ids: Vec<u64>) -> Self {
+        // Order the ranges by their `from` value, then fold each into the
+        // last merged range if they overlap or touch.
+        let mut ranges = parsed_ranges.clone();
+        ranges.sort_by_key(|range| range.from);
+
+        let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.from <= last.to.saturating_add(1) => {
+                    last.to = max(last.to, range.to);
+                }
+                _ => merged.push(range),
+            }
+        }
+
+        DB {
+            ranges: merged,
+            parsed_ranges,
+            ids,
+        }
+    }
+
+    /// Finds the merged range containing `id`, if the ingredient is fresh.
+    pub fn query(&self, id: u64) -> Option<&Range> {
+        let idx = self.ranges.partition_point(|range| range.to < id);
+        self.ranges.get(idx).filter(|range| range.contains(&id))
+    }
+
+    /// Lists the ranges from the input which contain `id`.
+    pub fn covering(&self, id: u64) -> Vec<&Range> {
+        self.parsed_ranges
+            .iter()
+            .filter(|range| range.contains(&id))
+            .collect()
+    }
+
+    /// Lists the spans of IDs between the merged ranges which are not fresh.
+    pub fn gaps(&self) -> Vec<Range> {
+        self.ranges
+            .windows(2)
+            .map(|pair| Range {
+                from: pair[0].to + 1,
+                to: pair[1].from - 1,
+            })
+            .collect()
+    }
+}
+
+/// The two parts of the database, separated by a blank line.
+#[derive(Debug, Clone, Copy)]
+enum Section {
+    Ranges,
+    Ids,
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Section::Ranges => write!(f, "ranges"),
+            Section::Ids => write!(f, "IDs"),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<DB, anyhow::Error> {
+    let mut section = Section::Ranges;
+    let mut ranges: Vec<Range> = vec![];
+    let mut ids: Vec<u64> = vec![];
+    // The first blank line after the IDs start, which is only allowed if
+    // nothing but blank lines follow it.
+    let mut blank = None;
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let parsed = match (section, line.is_empty()) {
+            (Section::Ranges, true) => {
+                section = Section::Ids;
+                Ok(())
+            }
+            (Section::Ranges, false) => parse_range(line).map(|range| ranges.push(range)),
+            (Section::Ids, true) => {
+                blank.get_or_insert(idx);
+                Ok(())
+            }
+            (Section::Ids, false) => match blank {
+                Some(blank) => {
+                    return Err(anyhow!(
+                        "Invalid line {} in the {} section: unexpected blank line",
+                        blank + 1,
+                        section
+                    ));
+                }
+                None => parse_id(line).map(|id| ids.push(id)),
+            },
+        };
+
+        parsed
+            .map_err(|e| anyhow!("Invalid line {} in the {} section: {}", idx + 1, section, e))?;
+    }
+
+    if let Section::Ranges = section {
+        return Err(anyhow!(
+            "Missing the blank line between the ranges and IDs sections"
+        ));
+    }
+
+    Ok(DB::new(ranges, ids))
+}
+
+fn parse_range(line: &str) -> Result<Range, anyhow::Error> {
+    let (from, to) = line
+        .split_once('-')
+        .ok_or_else(|| anyhow!("expected a range like `3-5`, found {:?}", line))?;
+    let (from, to) = (parse_id(from)?, parse_id(to)?);
+
+    if from > to {
+        return Err(anyhow!("range {:?} starts after it ends", line));
+    }
+
+    Ok(Range { from, to })
+}
+
+fn parse_id(s: &str) -> Result<u64, anyhow::Error> {
+    // `u64::from_str` accepts a leading `+`, which is not a valid ID.
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(anyhow!("expected an ID, found {:?}", s));
+    }
+
+    Ok(s.parse()?)
+}
+
+pub fn part_1(db: &DB) -> u64 {
+    db.ids.iter().filter(|&&id| db.query(id).is_some()).count() as u64
+}
+
+pub fn part_2(db: &DB) -> u128 {
+    db.ranges.iter().map(Range::len).sum()
+}
+
+/// Answers questions about the database, one command per line.
+pub fn repl(db: &DB) -> Result<(), anyhow::Error> {
+    log::debug!("{} REPL", NAME);
+
+    crate::repl::run(REPL_HELP, |line| eval(db, line))
+}
+
+const REPL_HELP: &str = "Commands:
+  fresh <id>     is the ingredient fresh?
+  covering <id>  which ranges contain the ingredient
+  gaps           spans of IDs between the merged ranges
+  stats          range counts and total coverage";
+
+fn eval(db: &DB, line: &str) -> Result<String, anyhow::Error> {
+    let mut words = line.split_whitespace();
+    let command = words.next().unwrap_or_default();
+    let id = words.next().map(parse_id).transpose()?;
+
+    let output = match (command, id) {
+        ("fresh", Some(id)) => match db.query(id) {
+            Some(range) => format!("{} is fresh (merged range {})", id, range),
+            None => format!("{} is spoiled", id),
+        },
+        ("covering", Some(id)) => {
+            let ranges = db.covering(id);
+            if ranges.is_empty() {
+                format!("No ranges contain {}", id)
+            } else {
+                join_lines(ranges)
+            }
+        }
+        ("gaps", None) => {
+            let gaps = db.gaps();
+            if gaps.is_empty() {
+                "No gaps".to_string()
+            } else {
+                join_lines(&gaps)
+            }
+        }
+        ("stats", None) => format!(
+            "Ranges: {}\nMerged ranges: {}\nFresh IDs: {}",
+            db.parsed_ranges.len(),
+            db.ranges.len(),
+            part_2(db)
+        ),
+        _ => return Err(anyhow!("Unknown command {:?}. Try `help`.", line)),
+    };
+
+    Ok(output)
+}
+
+fn join_lines(items: impl IntoIterator<Item = impl fmt::Display>) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let db = parse_input(TEST_INPUT).unwrap();
+        let result = part_1(&db);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let db = parse_input(TEST_INPUT).unwrap();
+        let result = part_2(&db);
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn test_part_2_full_range() {
+        let db = parse_input("0-18446744073709551615\n5-10\n\n1").unwrap();
+        assert_eq!(part_2(&db), u128::from(u64::MAX) + 1);
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        let db = parse_input(TEST_INPUT).unwrap();
+        let spans: Vec<(u64, u64)> = db.ranges.iter().map(|r| (r.from, r.to)).collect();
+        assert_eq!(spans, vec![(3, 5), (10, 20)]);
+
+        // Touching ranges are merged too.
+        let db = parse_input("1-2\n3-4\n6-6\n\n1").unwrap();
+        let spans: Vec<(u64, u64)> = db.ranges.iter().map(|r| (r.from, r.to)).collect();
+        assert_eq!(spans, vec![(1, 4), (6, 6)]);
+    }
+
+    #[test]
+    fn test_query() {
+        let db = parse_input(TEST_INPUT).unwrap();
+        let fresh: Vec<u64> = (0..=22).filter(|&id| db.query(id).is_some()).collect();
+        assert_eq!(
+            fresh,
+            vec![3, 4, 5, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+        );
+    }
+
+    #[test]
+    fn test_parse_input_invalid() {
+        let err = |input: &str| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("3 - 5\n\n1"),
+            "Invalid line 1 in the ranges section: expected an ID, found \"3 \""
+        );
+        assert_eq!(
+            err("3-5\n7\n\n1"),
+            "Invalid line 2 in the ranges section: expected a range like `3-5`, found \"7\""
+        );
+        assert_eq!(
+            err("5-3\n\n1"),
+            "Invalid line 1 in the ranges section: range \"5-3\" starts after it ends"
+        );
+        assert_eq!(
+            err("3-5\n\n1\n4-6"),
+            "Invalid line 4 in the IDs section: expected an ID, found \"4-6\""
+        );
+        assert_eq!(
+            err("3-5\n10-14"),
+            "Missing the blank line between the ranges and IDs sections"
+        );
+    }
+
+    #[test]
+    fn test_parse_raw_input() {
+        let db = Puzzle::parse("3-5\n\n").unwrap();
+        assert!(db.ids.is_empty());
+        assert_eq!(part_2(&db), 3);
+
+        let db = Puzzle::parse("3-5\n10-14\n\n1\n5\n\n").unwrap();
+        assert_eq!(db.ids, vec![1, 5]);
+
+        let err = Puzzle::parse("3-5\n\n1\n\n\n5\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid line 4 in the IDs section: unexpected blank line"
+        );
+
+        let err = Puzzle::parse("\n3-5\n\n1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid line 2 in the IDs section: expected an ID, found \"3-5\""
+        );
+    }
+
+    #[test]
+    fn test_parse_input_crlf() {
+        let db = parse_input("3-5\r\n\r\n4\r\n").unwrap();
+        assert_eq!(db.ids, vec![4]);
+        assert_eq!(part_1(&db), 1);
+    }
+
+    #[test]
+    fn test_eval() {
+        let db = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            eval(&db, "fresh 17").unwrap(),
+            "17 is fresh (merged range 10-20)"
+        );
+        assert_eq!(eval(&db, "fresh 8").unwrap(), "8 is spoiled");
+        assert_eq!(eval(&db, "covering 16").unwrap(), "16-20\n12-18");
+        assert_eq!(eval(&db, "covering 1").unwrap(), "No ranges contain 1");
+        assert_eq!(eval(&db, "gaps").unwrap(), "6-9");
+        assert_eq!(
+            eval(&db, "stats").unwrap(),
+            "Ranges: 4\nMerged ranges: 2\nFresh IDs: 14"
+        );
+        assert!(eval(&db, "fresh").is_err());
+        assert!(eval(&db, "fresh -1").is_err());
+        assert!(eval(&db, "gaps 3").is_err());
+    }
+}
@@ -0,0 +1,492 @@
+use crate::puzzle::Solution;
+use anyhow::anyhow;
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{Write, stdout},
+    ops::Range,
+};
+
+const NAME: &str = "Day 6";
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Worksheet;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Worksheet, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(worksheet: &Worksheet) -> Result<String, anyhow::Error> {
+        Ok(part_1(worksheet)?.to_string())
+    }
+
+    fn part_2(worksheet: &Worksheet) -> Result<String, anyhow::Error> {
+        Ok(part_2(worksheet)?.to_string())
+    }
+}
+
+/// Prints each problem as an equation, with the columns it was read from.
+pub fn explain(worksheet: &Worksheet) -> Result<(), anyhow::Error> {
+    log::debug!("{} explain", NAME);
+
+    let mut out = stdout().lock();
+    for (part, problems) in [(1, worksheet.row_wise()?), (2, worksheet.column_wise()?)] {
+        writeln!(out, "Part {}", part)?;
+        for problem in problems {
+            writeln!(
+                out,
+                "columns {}: {} = {}",
+                display_columns(&problem.columns),
+                problem,
+                problem.calculate()?
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// An operation which folds a problem's numbers together.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Op {
+    Product,
+    Sum,
+    Difference,
+    Quotient,
+    Max,
+    Min,
+}
+
+impl Op {
+    const ALL: [Op; 6] = [
+        Op::Product,
+        Op::Sum,
+        Op::Difference,
+        Op::Quotient,
+        Op::Max,
+        Op::Min,
+    ];
+
+    /// The glyph for the operation in the default table.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Op::Product => "*",
+            Op::Sum => "+",
+            Op::Difference => "-",
+            Op::Quotient => "/",
+            Op::Max => "max",
+            Op::Min => "min",
+        }
+    }
+
+    /// Folds `a` and `b` together, or `None` if the result does not fit or is
+    /// undefined.
+    fn apply(&self, a: u128, b: u128) -> Option<u128> {
+        match self {
+            Op::Product => a.checked_mul(b),
+            Op::Sum => a.checked_add(b),
+            Op::Difference => a.checked_sub(b),
+            Op::Quotient => a.checked_div(b),
+            Op::Max => Some(a.max(b)),
+            Op::Min => Some(a.min(b)),
+        }
+    }
+}
+
+/// OpTable maps the glyphs on the last line of a worksheet to operations.
+/// A glyph is a run of non-space characters, such as `*` or `max`.
+#[derive(Debug, Clone)]
+pub struct OpTable(HashMap<String, Op>);
+
+impl OpTable {
+    pub fn new<'a>(entries: impl IntoIterator<Item = (&'a str, Op)>) -> Self {
+        OpTable(
+            entries
+                .into_iter()
+                .map(|(glyph, op)| (glyph.to_string(), op))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, glyph: &str) -> Option<Op> {
+        self.0.get(glyph).copied()
+    }
+}
+
+impl Default for OpTable {
+    /// The puzzle's `*` and `+`, plus `-`, `/`, `max` and `min`.
+    fn default() -> Self {
+        OpTable::new(Op::ALL.iter().map(|op| (op.symbol(), *op)))
+    }
+}
+
+/// One problem on the worksheet: numbers folded together by an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub nums: Vec<u64>,
+    pub op: Op,
+    /// Where the problem sits in the worksheet, counting columns from 0.
+    pub columns: Range<usize>,
+}
+
+impl Problem {
+    /// Folds the numbers left to right with the problem's operation. Results
+    /// are widened to `u128`, and anything which still overflows, goes below
+    /// zero or divides by zero is an error.
+    pub fn calculate(&self) -> Result<u128, anyhow::Error> {
+        let mut nums = self.nums.iter().map(|&n| n as u128);
+        let first = nums
+            .next()
+            .ok_or_else(|| anyhow!("Problem has no numbers"))?;
+
+        nums.try_fold(first, |acc, n| self.op.apply(acc, n))
+            .ok_or_else(|| anyhow!("Cannot calculate {:?} of {:?}", self.op, self.nums))
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nums: Vec<String> = self.nums.iter().map(|n| n.to_string()).collect();
+        write!(f, "{}", nums.join(&format!(" {} ", self.op.symbol())))
+    }
+}
+
+/// A run of columns holding one problem, with the operator found beneath it.
+#[derive(Debug, Clone)]
+struct Block {
+    columns: Range<usize>,
+    op: Op,
+}
+
+/// The worksheet split into blocks of columns, one block per problem.
+///
+/// Blocks are separated by columns which are blank on every line. The
+/// numbers in a block can be read along the rows or down the columns.
+#[derive(Debug)]
+pub struct Worksheet {
+    /// Every line but the last, as characters.
+    num_lines: Vec<Vec<char>>,
+    blocks: Vec<Block>,
+}
+
+impl Worksheet {
+    /// Problems with one number per line, as read for part one.
+    pub fn row_wise(&self) -> Result<Vec<Problem>, anyhow::Error> {
+        self.blocks
+            .iter()
+            .map(|block| {
+                let nums = self
+                    .num_lines
+                    .iter()
+                    .enumerate()
+                    .map(|(line, chars)| {
+                        number(chars[block.columns.clone()].iter()).ok_or_else(|| {
+                            anyhow!(
+                                "No number on line {} in columns {}",
+                                line + 1,
+                                display_columns(&block.columns)
+                            )
+                        })?
+                    })
+                    .collect::<Result<_, anyhow::Error>>()?;
+
+                Ok(Problem {
+                    nums,
+                    op: block.op,
+                    columns: block.columns.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Problems with one number per column, read top to bottom and right to
+    /// left, as read for part two.
+    pub fn column_wise(&self) -> Result<Vec<Problem>, anyhow::Error> {
+        self.blocks
+            .iter()
+            .map(|block| {
+                let nums = block
+                    .columns
+                    .clone()
+                    .rev()
+                    .map(|col| {
+                        number(self.num_lines.iter().map(|chars| &chars[col]))
+                            .ok_or_else(|| anyhow!("No number in column {}", col + 1))?
+                    })
+                    .collect::<Result<_, anyhow::Error>>()?;
+
+                Ok(Problem {
+                    nums,
+                    op: block.op,
+                    columns: block.columns.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reads the digits in `chars`, skipping spaces. `None` if there are none.
+fn number<'a>(chars: impl Iterator<Item = &'a char>) -> Option<Result<u64, anyhow::Error>> {
+    let digits: String = chars.filter(|c| **c != ' ').collect();
+    if digits.is_empty() {
+        None
+    } else {
+        Some(digits.parse().map_err(anyhow::Error::from))
+    }
+}
+
+/// Formats columns counted from 1, as an editor would show them.
+fn display_columns(columns: &Range<usize>) -> String {
+    format!("{}-{}", columns.start + 1, columns.end)
+}
+
+pub fn parse_input(input: &str) -> Result<Worksheet, anyhow::Error> {
+    parse_worksheet(input, &OpTable::default())
+}
+
+/// Parses a worksheet whose operators are looked up in `ops`.
+pub fn parse_worksheet(input: &str, ops: &OpTable) -> Result<Worksheet, anyhow::Error> {
+    let lines: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    let (ops_line, num_lines) = lines
+        .split_last()
+        .ok_or_else(|| anyhow!("Worksheet is empty"))?;
+
+    let width = ops_line.len();
+    if let Some((idx, line)) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, line)| line.len() != width)
+    {
+        return Err(anyhow!(
+            "Ragged worksheet: line {} is {} columns wide, expected {}",
+            idx + 1,
+            line.len(),
+            width
+        ));
+    }
+
+    for (idx, line) in num_lines.iter().enumerate() {
+        if let Some(col) = line.iter().position(|&c| c != ' ' && !c.is_ascii_digit()) {
+            return Err(anyhow!(
+                "Unexpected {:?} on line {}, column {}",
+                line[col],
+                idx + 1,
+                col + 1
+            ));
+        }
+    }
+
+    // Split into blocks at columns which are blank on every line.
+    let mut blocks = Vec::new();
+    let mut block_start = None;
+    for col in 0..=width {
+        let blank = col == width || lines.iter().all(|line| line[col] == ' ');
+        match (blank, block_start) {
+            (false, None) => block_start = Some(col),
+            (true, Some(start)) => {
+                blocks.push(parse_block(ops_line, start..col, ops)?);
+                block_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Worksheet {
+        num_lines: num_lines.to_vec(),
+        blocks,
+    })
+}
+
+fn parse_block(
+    ops_line: &[char],
+    columns: Range<usize>,
+    ops: &OpTable,
+) -> Result<Block, anyhow::Error> {
+    let line: String = ops_line[columns.clone()].iter().collect();
+    let glyphs: Vec<&str> = line.split_whitespace().collect();
+
+    let op = match glyphs[..] {
+        [glyph] => ops.get(glyph).ok_or_else(|| {
+            anyhow!(
+                "Unknown operator {:?} in columns {}",
+                glyph,
+                display_columns(&columns)
+            )
+        })?,
+        [] => {
+            return Err(anyhow!(
+                "Missing operator in columns {}",
+                display_columns(&columns)
+            ));
+        }
+        _ => {
+            return Err(anyhow!(
+                "More than one operator in columns {}",
+                display_columns(&columns)
+            ));
+        }
+    };
+
+    Ok(Block { columns, op })
+}
+
+pub fn part_1(worksheet: &Worksheet) -> Result<u128, anyhow::Error> {
+    solve(&worksheet.row_wise()?)
+}
+
+pub fn part_2(worksheet: &Worksheet) -> Result<u128, anyhow::Error> {
+    solve(&worksheet.column_wise()?)
+}
+
+fn solve(problems: &[Problem]) -> Result<u128, anyhow::Error> {
+    problems.iter().try_fold(0_u128, |total, problem| {
+        total
+            .checked_add(problem.calculate()?)
+            .ok_or_else(|| anyhow!("Grand total overflowed"))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let worksheet = parse_input(TEST_INPUT).unwrap();
+        let result = part_1(&worksheet).unwrap();
+        assert_eq!(result, 4277556);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let worksheet = parse_input(TEST_INPUT).unwrap();
+        let result = part_2(&worksheet).unwrap();
+        assert_eq!(result, 3263827);
+    }
+
+    #[test]
+    fn test_views() {
+        let worksheet = parse_input(TEST_INPUT).unwrap();
+
+        let columns: Vec<Range<usize>> =
+            worksheet.blocks.iter().map(|b| b.columns.clone()).collect();
+        assert_eq!(columns, vec![0..3, 4..7, 8..11, 12..15]);
+
+        assert_eq!(
+            worksheet.row_wise().unwrap()[0],
+            Problem {
+                nums: vec![123, 45, 6],
+                op: Op::Product,
+                columns: 0..3,
+            }
+        );
+        assert_eq!(
+            worksheet.column_wise().unwrap()[3],
+            Problem {
+                nums: vec![4, 431, 623],
+                op: Op::Sum,
+                columns: 12..15,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_input_invalid() {
+        let err = |input: &str| parse_input(input).unwrap_err().to_string();
+
+        assert_eq!(
+            err("12 3\n4   5\n*   +"),
+            "Ragged worksheet: line 1 is 4 columns wide, expected 5"
+        );
+        assert_eq!(
+            err("12  3\n4   5\n*    "),
+            "Missing operator in columns 5-5"
+        );
+        assert_eq!(
+            err("12  3\n4   5\n*   ="),
+            "Unknown operator \"=\" in columns 5-5"
+        );
+        assert_eq!(
+            err("1x  3\n4   5\n*   +"),
+            "Unexpected 'x' on line 1, column 2"
+        );
+        assert_eq!(err(""), "Worksheet is empty");
+    }
+
+    #[test]
+    fn test_row_wise_missing_number() {
+        let worksheet = parse_input("12 3\n    \n*  +").unwrap();
+        assert_eq!(
+            worksheet.row_wise().unwrap_err().to_string(),
+            "No number on line 2 in columns 1-2"
+        );
+    }
+
+    #[test]
+    fn test_more_ops() {
+        let worksheet = parse_input("20 9   7   5\n 5 3   2   8\n-  / max min").unwrap();
+        let problems = worksheet.row_wise().unwrap();
+        let results: Vec<u128> = problems
+            .iter()
+            .map(|problem| problem.calculate().unwrap())
+            .collect();
+        assert_eq!(results, vec![15, 3, 7, 5]);
+        assert_eq!(problems[2].to_string(), "7 max 2");
+
+        assert_eq!(
+            parse_input("7 5   \n2 8   \nmaxmin")
+                .unwrap_err()
+                .to_string(),
+            "Unknown operator \"maxmin\" in columns 1-6"
+        );
+    }
+
+    #[test]
+    fn test_custom_op_table() {
+        let ops = OpTable::new([("x", Op::Product), ("sum", Op::Sum)]);
+        let worksheet = parse_worksheet("3 4  \n5 6  \nx sum", &ops).unwrap();
+        assert_eq!(part_1(&worksheet).unwrap(), 25);
+        assert!(parse_worksheet("3\n5\n*", &ops).is_err());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let calculate = |nums: Vec<u64>, op| {
+            Problem {
+                nums,
+                op,
+                columns: 0..1,
+            }
+            .calculate()
+        };
+
+        // Products beyond `u64` are promoted rather than wrapping.
+        assert_eq!(
+            calculate(vec![u64::MAX, 2], Op::Product).unwrap(),
+            u64::MAX as u128 * 2
+        );
+        assert!(calculate(vec![u64::MAX; 3], Op::Product).is_err());
+        assert!(calculate(vec![1, 2], Op::Difference).is_err());
+        assert!(calculate(vec![1, 0], Op::Quotient).is_err());
+    }
+
+    #[test]
+    fn test_display_problem() {
+        let worksheet = parse_input(TEST_INPUT).unwrap();
+        let problem = &worksheet.column_wise().unwrap()[3];
+        assert_eq!(problem.to_string(), "4 + 431 + 623");
+        assert_eq!(display_columns(&problem.columns), "13-15");
+    }
+}
@@ -1,33 +1,44 @@
-use crate::{puzzle::Parts, timer::format_duration};
-use std::{fs::read_to_string, time::Instant};
+use crate::puzzle::{Solution, Unimplemented};
 
 const NAME: &str = "Day 7";
-const INPUT_FILE: &str = "inputs/day_07.txt";
-
-pub fn run(parts: Parts) {
-    log::debug!("{}", NAME);
-
-    let field = parse_input(
-        &read_to_string(INPUT_FILE)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {}", INPUT_FILE, e)),
-    )
-    .expect("Failed to parse");
-
-    let start = Instant::now();
-    match parts {
-        Parts::Both => {
-            println!("{}", part_1(&field));
-            println!("{}", part_2(&field));
-        }
-        Parts::First => {
-            println!("{}", part_1(&field));
-        }
-        Parts::Second => {
-            println!("{}", part_2(&field));
-        }
-    };
-    let duration = start.elapsed();
-    log::debug!("Duration {}", format_duration(duration));
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Field;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Field, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(field: &Field) -> Result<String, anyhow::Error> {
+        Ok(part_1(field).to_string())
+    }
+
+    fn part_2(field: &Field) -> Result<String, anyhow::Error> {
+        Ok(part_2(field)?.to_string())
+    }
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -38,7 +49,7 @@ enum Entity {
 }
 
 #[derive(Debug, Clone)]
-struct Field {
+pub struct Field {
     rows: Vec<Vec<(usize, Entity)>>,
 }
 
@@ -52,7 +63,7 @@ impl Field {
     }
 }
 
-fn parse_input(input: &str) -> Result<Field, anyhow::Error> {
+pub fn parse_input(input: &str) -> Result<Field, anyhow::Error> {
     let rows = input
         .lines()
         .map(|line| {
@@ -70,7 +81,7 @@ fn parse_input(input: &str) -> Result<Field, anyhow::Error> {
     Ok(Field { rows })
 }
 
-fn part_1(field: &Field) -> u64 {
+pub fn part_1(field: &Field) -> u64 {
     let mut field = field.clone();
 
     let mut n_splits = 0;
@@ -79,7 +90,6 @@ fn part_1(field: &Field) -> u64 {
         let entities: Vec<_> = field.rows[row].clone();
 
         for (col, ent) in entities {
-            dbg!(&col, &ent);
             match ent {
                 Entity::Source => {
                     field.place_beam(row + 1, col);
@@ -114,47 +124,21 @@ fn part_1(field: &Field) -> u64 {
         }
     }
 
-    dbg!(field);
-
     n_splits
 }
 
-fn part_2(field: &Field) -> u64 {
-    0
+pub fn part_2(_field: &Field) -> Result<u64, anyhow::Error> {
+    Err(Unimplemented.into())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const TEST_INPUT: &str = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
     #[test]
     fn test_part_1() {
         let field = parse_input(TEST_INPUT).unwrap();
         let result = part_1(&field);
         assert_eq!(result, 21);
     }
-
-    #[test]
-    fn test_part_2() {
-        let field = parse_input(TEST_INPUT).unwrap();
-        let result = part_2(&field);
-        assert_eq!(result, 0);
-    }
 }
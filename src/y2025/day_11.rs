@@ -0,0 +1,39 @@
+use crate::puzzle::{Solution, Unimplemented};
+
+const NAME: &str = "Day 11";
+
+/// The example from the puzzle description.
+const TEST_INPUT: &str = "";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<u32>;
+
+    const NAME: &'static str = NAME;
+    const EXAMPLE: &'static str = TEST_INPUT;
+
+    fn parse(input: &str) -> Result<Vec<u32>, anyhow::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(parsed_input: &Vec<u32>) -> Result<String, anyhow::Error> {
+        Ok(part_1(parsed_input)?.to_string())
+    }
+
+    fn part_2(parsed_input: &Vec<u32>) -> Result<String, anyhow::Error> {
+        Ok(part_2(parsed_input)?.to_string())
+    }
+}
+
+pub fn parse_input(_input: &str) -> Result<Vec<u32>, anyhow::Error> {
+    Err(Unimplemented.into())
+}
+
+pub fn part_1(_problems: &[u32]) -> Result<u64, anyhow::Error> {
+    Err(Unimplemented.into())
+}
+
+pub fn part_2(_problems: &[u32]) -> Result<u64, anyhow::Error> {
+    Err(Unimplemented.into())
+}
@@ -0,0 +1,85 @@
+//! Solutions to the 2025 event.
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+use crate::{
+    puzzle::{DayVisitor, Parts, Solution, load},
+    runner::{self, Options, PartReport, unimplemented},
+    trace::TraceFormat,
+};
+use anyhow::anyhow;
+
+pub const YEAR: u16 = 2025;
+
+/// Shows `visitor` every day with a solution. Days 8 to 12 are still stubs
+/// and are left out, so they are reported as unimplemented.
+pub fn for_each_day(visitor: &mut impl DayVisitor) {
+    visitor.visit::<day_01::Puzzle>(YEAR, 1);
+    visitor.visit::<day_02::Puzzle>(YEAR, 2);
+    visitor.visit::<day_03::Puzzle>(YEAR, 3);
+    visitor.visit::<day_04::Puzzle>(YEAR, 4);
+    visitor.visit::<day_05::Puzzle>(YEAR, 5);
+    visitor.visit::<day_06::Puzzle>(YEAR, 6);
+    visitor.visit::<day_07::Puzzle>(YEAR, 7);
+}
+
+pub fn solve(day: u8, parts: Parts, options: Options) -> Vec<PartReport> {
+    struct Solve {
+        day: u8,
+        parts: Parts,
+        options: Options,
+        reports: Option<Vec<PartReport>>,
+    }
+
+    impl DayVisitor for Solve {
+        fn visit<S: Solution>(&mut self, year: u16, day: u8) {
+            if day == self.day {
+                self.reports = Some(runner::run::<S>(year, day, self.parts, self.options));
+            }
+        }
+    }
+
+    let mut solve = Solve {
+        day,
+        parts,
+        options,
+        reports: None,
+    };
+    for_each_day(&mut solve);
+    solve
+        .reports
+        .unwrap_or_else(|| unimplemented(YEAR, day, parts))
+}
+
+pub fn trace(day: u8, format: TraceFormat) -> Result<(), anyhow::Error> {
+    match day {
+        1 => day_01::trace(&load::<day_01::Puzzle>(YEAR, day)?, format),
+        _ => Err(anyhow!("Day {} does not support tracing.", day)),
+    }
+}
+
+pub fn explain(day: u8) -> Result<(), anyhow::Error> {
+    match day {
+        3 => day_03::explain(&load::<day_03::Puzzle>(YEAR, day)?),
+        6 => day_06::explain(&load::<day_06::Puzzle>(YEAR, day)?),
+        _ => Err(anyhow!("Day {} does not support explaining.", day)),
+    }
+}
+
+pub fn repl(day: u8) -> Result<(), anyhow::Error> {
+    match day {
+        5 => day_05::repl(&load::<day_05::Puzzle>(YEAR, day)?),
+        _ => Err(anyhow!("Day {} does not have a REPL.", day)),
+    }
+}
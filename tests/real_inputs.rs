@@ -0,0 +1,97 @@
+//! Runs every registered day against its real input and checks the answers
+//! saved next to it, making the git-ignored inputs a local regression
+//! suite. Days without an input or saved answers are skipped with the
+//! reason, which `cargo test --test real_inputs -- --nocapture` shows.
+//!
+//! Answers live in `<input dir>/<year>/day_XX.answers`: part one on the
+//! first line and part two on the second. Set `AOC_INPUT_DIR` to read them
+//! from somewhere other than `inputs`.
+
+use advent_2025::{
+    puzzle::{self, DayVisitor, Parts, Solution},
+    runner::{self, Options, Status},
+    y2025,
+};
+use std::{env, path::PathBuf};
+
+#[derive(Default)]
+struct Check {
+    passed: Vec<String>,
+    skipped: Vec<String>,
+    failed: Vec<String>,
+}
+
+impl DayVisitor for Check {
+    fn visit<S: Solution>(&mut self, year: u16, day: u8) {
+        let name = format!("{} day {}", year, day);
+
+        let input = puzzle::input_path(year, day);
+        if !input.exists() {
+            self.skipped
+                .push(format!("{}: no input at {}", name, input.display()));
+            return;
+        }
+        let expected = match puzzle::expected_answers(year, day) {
+            Ok(expected) => expected,
+            Err(e) => {
+                self.failed.push(format!("{}: {:#}", name, e));
+                return;
+            }
+        };
+        if expected.iter().all(Option::is_none) {
+            self.skipped.push(format!(
+                "{}: no answers at {}",
+                name,
+                input.with_extension("answers").display()
+            ));
+            return;
+        }
+
+        for report in runner::run::<S>(year, day, Parts::Both, Options::default()) {
+            let name = format!("{} part {}", name, report.part);
+            let Some(expected) = &expected[usize::from(report.part) - 1] else {
+                self.skipped.push(format!("{}: no saved answer", name));
+                continue;
+            };
+            match (report.status, report.answer) {
+                (Status::Ok, Some(answer)) if answer == *expected => self.passed.push(name),
+                (Status::Unimplemented, _) => {
+                    self.skipped.push(format!("{}: not implemented", name))
+                }
+                (Status::Ok, answer) => self.failed.push(format!(
+                    "{}: expected {}, got {}",
+                    name,
+                    expected,
+                    answer.unwrap_or_default()
+                )),
+                (_, _) => {
+                    self.failed
+                        .push(format!("{}: {}", name, report.error.unwrap_or_default()))
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_real_inputs() {
+    if let Some(dir) = env::var_os("AOC_INPUT_DIR") {
+        puzzle::set_input_dir(PathBuf::from(dir));
+    }
+
+    let mut check = Check::default();
+    y2025::for_each_day(&mut check);
+
+    for passed in &check.passed {
+        eprintln!("ok: {}", passed);
+    }
+    for skipped in &check.skipped {
+        eprintln!("skipped: {}", skipped);
+    }
+    assert!(
+        check.failed.is_empty(),
+        "{} answers did not match:\n{}",
+        check.failed.len(),
+        check.failed.join("\n")
+    );
+}